@@ -0,0 +1,170 @@
+//! Compare `Lexer::tokenize`, which reserves room for the tokens upfront, with
+//! collecting the lazy token iterator, which grows the vector as it goes.
+//!
+//! Run with `cargo bench --bench tokenize`.
+
+use std::alloc::GlobalAlloc;
+use std::alloc::Layout;
+use std::alloc::System;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+use std::time::Instant;
+
+use php_parser_rs::lexer::token::Token;
+use php_parser_rs::lexer::Lexer;
+
+/// Counts every allocation and reallocation made by the program.
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+const RUNS: usize = 10;
+
+const CLASS: &str = r#"
+final class User%d extends Model implements JsonSerializable
+{
+    use HasAttributes;
+
+    private const TABLE = 'users';
+
+    public function __construct(
+        private readonly string $name,
+        private ?string $email = null,
+        protected array $roles = [],
+    ) {
+    }
+
+    public function jsonSerialize(): array
+    {
+        // only expose what is safe to share.
+        return [
+            'name' => $this->name,
+            'email' => $this->email ?? "unknown@{$this->domain()}",
+            'admin' => in_array('admin', $this->roles, true),
+        ];
+    }
+
+    public static function find(int $id): ?static
+    {
+        foreach (self::query()->where('id', '=', $id)->get() as $row) {
+            if ($row['deleted_at'] !== null) {
+                continue;
+            }
+
+            return new static($row['name'], $row['email'] ?? null);
+        }
+
+        return null;
+    }
+}
+"#;
+
+fn main() {
+    let lexer = Lexer::new();
+
+    let mut generated = String::from("<?php\n\nnamespace App\\Models;\n");
+    for index in 0..2_000 {
+        generated.push_str(&CLASS.replace("%d", &index.to_string()));
+    }
+
+    let fixtures = fixtures();
+
+    println!(
+        "{:<24} {:>10} {:>10} {:>12} {:>12}",
+        "input", "bytes", "tokens", "collect", "tokenize"
+    );
+
+    compare(&lexer, "generated", &[generated.into_bytes()]);
+    compare(&lexer, "fixtures", &fixtures);
+}
+
+/// The code of every fixture that lexes without an error.
+fn fixtures() -> Vec<Vec<u8>> {
+    let directory = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures");
+
+    let mut entries: Vec<PathBuf> = fs::read_dir(directory)
+        .unwrap()
+        .map(|entry| entry.unwrap().path().join("code.php"))
+        .collect();
+    entries.sort();
+
+    entries
+        .into_iter()
+        .filter_map(|path| fs::read(path).ok())
+        .filter(|code| Lexer::new().tokenize(code).is_ok())
+        .collect()
+}
+
+fn compare(lexer: &Lexer, name: &str, inputs: &[Vec<u8>]) {
+    let bytes: usize = inputs.iter().map(Vec::len).sum();
+    let tokens: usize = inputs
+        .iter()
+        .map(|input| lexer.tokenize(input).unwrap().len())
+        .sum();
+
+    let (collect_allocations, collect_time) = measure(inputs, |input| {
+        lexer
+            .iter(input)
+            .collect::<Result<Vec<Token>, _>>()
+            .unwrap()
+    });
+    let (tokenize_allocations, tokenize_time) =
+        measure(inputs, |input| lexer.tokenize(input).unwrap());
+
+    println!(
+        "{:<24} {:>10} {:>10} {:>12} {:>12}",
+        name, bytes, tokens, collect_allocations, tokenize_allocations
+    );
+    println!(
+        "{:<24} {:>10} {:>10} {:>12.2?} {:>12.2?}",
+        "", "", "", collect_time, tokenize_time
+    );
+    println!(
+        "{:<24} {:>10.2} bytes per token",
+        "",
+        bytes as f64 / tokens as f64
+    );
+}
+
+/// Lex every input, returning the allocations made by a single pass,
+/// and the fastest of several passes.
+fn measure(inputs: &[Vec<u8>], lex: impl Fn(&[u8]) -> Vec<Token>) -> (usize, Duration) {
+    let mut allocations = 0;
+    let mut fastest = Duration::MAX;
+
+    for _ in 0..RUNS {
+        let before = ALLOCATIONS.load(Ordering::Relaxed);
+        let start = Instant::now();
+
+        for input in inputs {
+            std::hint::black_box(lex(input));
+        }
+
+        fastest = fastest.min(start.elapsed());
+        allocations = ALLOCATIONS.load(Ordering::Relaxed) - before;
+    }
+
+    (allocations, fastest)
+}
@@ -3,7 +3,8 @@ use std::fs::read_dir;
 use std::io;
 use std::path::PathBuf;
 
-use php_parser_rs::parse;
+use php_parser_rs::parse_with_config;
+use php_parser_rs::ParserConfig;
 
 fn main() -> io::Result<()> {
     let manifest = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
@@ -19,6 +20,7 @@ fn main() -> io::Result<()> {
         let code_filename = entry.join("code.php");
         let ast_filename = entry.join("ast.txt");
         let error_filename = entry.join("error.txt");
+        let config_filename = entry.join("config.json");
 
         if !code_filename.exists() {
             continue;
@@ -33,8 +35,12 @@ fn main() -> io::Result<()> {
         }
 
         let code = std::fs::read_to_string(&code_filename)?;
+        let config: ParserConfig = match std::fs::read_to_string(&config_filename) {
+            Ok(config) => serde_json::from_str(&config)?,
+            Err(_) => ParserConfig::default(),
+        };
 
-        match parse(&code) {
+        match parse_with_config(&code, config) {
             Ok(ast) => {
                 std::fs::write(ast_filename, format!("{:#?}\n", ast))?;
                 println!("✅ generated `ast.txt` for `{}`", entry.to_string_lossy());
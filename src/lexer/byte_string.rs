@@ -72,7 +72,9 @@ impl Serialize for ByteString {
     where
         S: serde::Serializer,
     {
-        serializer.serialize_str(&self.to_string())
+        // PHP source is not required to be valid UTF-8, so invalid sequences
+        // are replaced rather than failing to serialize.
+        serializer.serialize_str(&String::from_utf8_lossy(&self.bytes))
     }
 }
 
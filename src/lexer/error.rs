@@ -12,6 +12,8 @@ pub enum SyntaxError {
     InvalidHaltCompiler(Span),
     InvalidOctalEscape(Span),
     InvalidOctalLiteral(Span),
+    InvalidNumericLiteral(Span),
+    InvalidNumericSeparator(Span),
     InvalidUnicodeEscape(Span),
     UnpredictableState(Span),
     InvalidDocIndentation(Span),
@@ -28,6 +30,8 @@ impl SyntaxError {
             Self::InvalidHaltCompiler(span) => *span,
             Self::InvalidOctalEscape(span) => *span,
             Self::InvalidOctalLiteral(span) => *span,
+            Self::InvalidNumericLiteral(span) => *span,
+            Self::InvalidNumericSeparator(span) => *span,
             Self::InvalidUnicodeEscape(span) => *span,
             Self::UnpredictableState(span) => *span,
             Self::InvalidDocIndentation(span) => *span,
@@ -70,6 +74,16 @@ impl Display for SyntaxError {
                 "Syntax Error: invalid octal literal on line {} column {}",
                 span.line, span.column
             ),
+            Self::InvalidNumericLiteral(span) => write!(
+                f,
+                "Syntax Error: invalid numeric literal on line {} column {}",
+                span.line, span.column
+            ),
+            Self::InvalidNumericSeparator(span) => write!(
+                f,
+                "Syntax Error: invalid numeric separator on line {} column {}",
+                span.line, span.column
+            ),
             Self::InvalidUnicodeEscape(span) => write!(
                 f,
                 "Syntax Error: invalid unicode escape on line {} column {}",
@@ -1,3 +1,6 @@
+use std::num::IntErrorKind;
+use std::num::ParseIntError;
+
 use crate::ident;
 use crate::ident_start;
 use crate::lexer::byte_string::ByteString;
@@ -9,13 +12,16 @@ use crate::lexer::state::State;
 use crate::lexer::token::DocStringIndentationKind;
 use crate::lexer::token::DocStringKind;
 use crate::lexer::token::OpenTagKind;
+use crate::lexer::token::Span;
 use crate::lexer::token::Token;
 use crate::lexer::token::TokenKind;
+use crate::lexer::trivia::TokenWithTrivia;
 
 pub mod byte_string;
 pub mod error;
 pub mod stream;
 pub mod token;
+pub mod trivia;
 
 mod state;
 
@@ -30,83 +36,220 @@ impl Lexer {
     }
 
     pub fn tokenize<B: ?Sized + AsRef<[u8]>>(&self, input: &B) -> SyntaxResult<Vec<Token>> {
-        let mut state = State::new(Source::new(input.as_ref()));
-        let mut tokens = Vec::new();
-
-        while !state.source.eof() {
-            match state.frame()? {
-                // The "Initial" state is used to parse inline HTML. It is essentially a catch-all
-                // state that will build up a single token buffer until it encounters an open tag
-                // of some description.
-                StackFrame::Initial => self.initial(&mut state, &mut tokens)?,
-                // The scripting state is entered when an open tag is encountered in the source code.
-                // This tells the lexer to start analysing characters at PHP tokens instead of inline HTML.
-                StackFrame::Scripting => {
-                    self.skip_whitespace(&mut state);
-
-                    // If we have consumed whitespace and then reached the end of the file, we should break.
-                    if state.source.eof() {
-                        break;
-                    }
+        let input = input.as_ref();
+
+        // PHP source averages roughly one token for every 4 to 6 bytes (see
+        // `benches/tokenize.rs`), so reserve for the denser end upfront to avoid
+        // repeatedly growing the vector on large files.
+        let mut tokens = Vec::with_capacity(input.len() / 4);
+        for token in self.iter(input) {
+            tokens.push(token?);
+        }
+
+        Ok(tokens)
+    }
+
+    /// Tokenize the given input without discarding anything, attaching whitespace and
+    /// comments to the surrounding tokens as trivia.
+    ///
+    /// This is slower than [`Lexer::tokenize`], but allows the original source to be
+    /// reconstructed exactly, which is useful for tools such as formatters.
+    pub fn tokenize_with_trivia<B: ?Sized + AsRef<[u8]>>(
+        &self,
+        input: &B,
+    ) -> SyntaxResult<Vec<TokenWithTrivia>> {
+        let input = input.as_ref();
+        let mut state = State::new(Source::new(input));
+        let mut tokens = Vec::with_capacity(input.len() / 4);
+        let mut buffer = Vec::new();
+
+        loop {
+            let more = self.step(&mut state, &mut buffer)?;
+            let end = state.source.span().position.min(input.len());
+
+            // tokens lexed in a single step are contiguous, so each one ends where
+            // the next begins, and the last one ends where the lexer stopped.
+            let ends = buffer
+                .iter()
+                .skip(1)
+                .map(|token| token.span.position)
+                .chain(std::iter::once(end))
+                .collect::<Vec<_>>();
+
+            tokens.extend(buffer.drain(..).zip(ends));
+
+            if !more {
+                break;
+            }
+        }
+
+        let span = state.source.span();
+        tokens.push((
+            Token {
+                kind: TokenKind::Eof,
+                span,
+                value: ByteString::default(),
+            },
+            input.len(),
+        ));
+
+        Ok(trivia::attach(input, tokens))
+    }
+
+    /// Create an iterator that lexes the given input lazily, one token (or
+    /// a small group of tokens) at a time, instead of buffering the whole file.
+    ///
+    /// The iterator always finishes with a `TokenKind::Eof` token, unless an
+    /// error is encountered, in which case the error is the last item yielded.
+    pub fn iter<'a, B: ?Sized + AsRef<[u8]>>(&self, input: &'a B) -> Tokens<'a> {
+        Tokens {
+            lexer: self.clone(),
+            state: State::new(Source::new(input.as_ref())),
+            buffer: Vec::new(),
+            position: 0,
+            finished: false,
+        }
+    }
+
+    /// Lex the next token(s) from the current state into the given buffer,
+    /// returning `false` once the end of the input has been reached.
+    fn step(&self, state: &mut State, tokens: &mut Vec<Token>) -> SyntaxResult<bool> {
+        if state.source.eof() {
+            return Ok(false);
+        }
 
-                    tokens.push(self.scripting(&mut state)?);
+        match state.frame()? {
+            // The "Initial" state is used to parse inline HTML. It is essentially a catch-all
+            // state that will build up a single token buffer until it encounters an open tag
+            // of some description.
+            StackFrame::Initial => self.initial(state, tokens)?,
+            // The scripting state is entered when an open tag is encountered in the source code.
+            // This tells the lexer to start analysing characters at PHP tokens instead of inline HTML.
+            StackFrame::Scripting => {
+                self.skip_whitespace(state);
+
+                // If we have consumed whitespace and then reached the end of the file, we should break.
+                if state.source.eof() {
+                    return Ok(false);
                 }
-                // The "Halted" state is entered when the `__halt_compiler` token is encountered.
-                // In this state, all the text that follows is no longer parsed as PHP as is collected
-                // into a single "InlineHtml" token (kind of cheating, oh well).
-                StackFrame::Halted => {
-                    tokens.push(Token {
-                        kind: TokenKind::InlineHtml,
-                        span: state.source.span(),
-                        value: state.source.read_remaining().into(),
-                    });
-                    break;
+
+                let token = self.scripting(state)?;
+                let halt = token.kind == TokenKind::HaltCompiler;
+
+                tokens.push(token);
+
+                if halt {
+                    self.halt_compiler(state, tokens)?;
                 }
-                // The double quote state is entered when inside a double-quoted string that
-                // contains variables.
-                StackFrame::DoubleQuote => self.double_quote(&mut state, &mut tokens)?,
-                // The shell exec state is entered when inside of a execution string (`).
-                StackFrame::ShellExec => self.shell_exec(&mut state, &mut tokens)?,
-                // The doc string state is entered when tokenizing heredocs and nowdocs.
-                StackFrame::DocString(kind, label, ..) => {
-                    let label = label.clone();
-
-                    match kind {
-                        DocStringKind::Heredoc => self.heredoc(&mut state, &mut tokens, label)?,
-                        DocStringKind::Nowdoc => self.nowdoc(&mut state, &mut tokens, label)?,
-                    }
+            }
+            // The "Halted" state is entered when the `__halt_compiler` token is encountered.
+            // In this state, all the text that follows is no longer parsed as PHP as is collected
+            // into a single "InlineHtml" token (kind of cheating, oh well).
+            StackFrame::Halted => {
+                tokens.push(Token {
+                    kind: TokenKind::InlineHtml,
+                    span: state.source.span(),
+                    value: state.source.read_remaining().into(),
+                });
+                return Ok(false);
+            }
+            // The double quote state is entered when inside a double-quoted string that
+            // contains variables.
+            StackFrame::DoubleQuote => self.double_quote(state, tokens)?,
+            // The shell exec state is entered when inside of a execution string (`).
+            StackFrame::ShellExec => self.shell_exec(state, tokens)?,
+            // The doc string state is entered when tokenizing heredocs and nowdocs.
+            StackFrame::DocString(kind, label, ..) => {
+                let label = label.clone();
+
+                match kind {
+                    DocStringKind::Heredoc => self.heredoc(state, tokens, label)?,
+                    DocStringKind::Nowdoc => self.nowdoc(state, tokens, label)?,
                 }
-                // LookingForProperty is entered inside double quotes,
-                // backticks, or a heredoc, expecting a variable name.
-                // If one isn't found, it switches to scripting.
-                StackFrame::LookingForVarname => {
-                    if let Some(token) = self.looking_for_varname(&mut state)? {
-                        tokens.push(token);
-                    }
+            }
+            // LookingForProperty is entered inside double quotes,
+            // backticks, or a heredoc, expecting a variable name.
+            // If one isn't found, it switches to scripting.
+            StackFrame::LookingForVarname => {
+                if let Some(token) = self.looking_for_varname(state)? {
+                    tokens.push(token);
                 }
-                // LookingForProperty is entered inside double quotes,
-                // backticks, or a heredoc, expecting an arrow followed by a
-                // property name.
-                StackFrame::LookingForProperty => {
-                    tokens.push(self.looking_for_property(&mut state)?);
+            }
+            // LookingForProperty is entered inside double quotes,
+            // backticks, or a heredoc, expecting an arrow followed by a
+            // property name.
+            StackFrame::LookingForProperty => {
+                tokens.push(self.looking_for_property(state)?);
+            }
+            StackFrame::VarOffset => {
+                if state.source.eof() {
+                    return Ok(false);
                 }
-                StackFrame::VarOffset => {
-                    if state.source.eof() {
-                        break;
-                    }
 
-                    tokens.push(self.var_offset(&mut state)?);
+                tokens.push(self.var_offset(state)?);
+            }
+        }
+
+        Ok(true)
+    }
+
+    /// Lex the `();` (or `() ?>`) following a `__halt_compiler` token, after which
+    /// the rest of the input is no longer lexed as PHP.
+    ///
+    /// If anything else follows, lexing carries on as normal and the parser reports
+    /// the invalid statement instead.
+    fn halt_compiler(&self, state: &mut State, tokens: &mut Vec<Token>) -> SyntaxResult<()> {
+        let expected: [fn(&TokenKind) -> bool; 3] = [
+            |kind| kind == &TokenKind::LeftParen,
+            |kind| kind == &TokenKind::RightParen,
+            |kind| matches!(kind, TokenKind::SemiColon | TokenKind::CloseTag),
+        ];
+
+        for expected in expected {
+            // comments may appear anywhere in between, e.g. `__halt_compiler(/* ... */);`.
+            let token = loop {
+                self.skip_whitespace(state);
+
+                if state.source.eof() {
+                    return Ok(());
+                }
+
+                let token = self.scripting(state)?;
+                if !matches!(
+                    token.kind,
+                    TokenKind::SingleLineComment
+                        | TokenKind::HashMarkComment
+                        | TokenKind::MultiLineComment
+                        | TokenKind::DocumentComment
+                ) {
+                    break token;
                 }
+
+                tokens.push(token);
+            };
+
+            let matched = expected(&token.kind);
+
+            tokens.push(token);
+
+            if !matched {
+                return Ok(());
             }
         }
 
-        tokens.push(Token {
-            kind: TokenKind::Eof,
-            span: state.source.span(),
-            value: ByteString::default(),
-        });
+        // PHP treats a single newline directly after `?>` as part of the tag,
+        // so it is not included in the data that follows.
+        if matches!(tokens.last(), Some(token) if token.kind == TokenKind::CloseTag) {
+            if state.source.at(b"\r\n", 2) {
+                state.source.skip(2);
+            } else if state.source.at(b"\n", 1) {
+                state.source.next();
+            }
+        }
 
-        Ok(tokens)
+        state.replace(StackFrame::Halted);
+
+        Ok(())
     }
 
     fn skip_whitespace(&self, state: &mut State) {
@@ -552,7 +695,13 @@ impl Lexer {
             }
             [b'}', ..] => {
                 state.source.next();
-                state.exit();
+
+                // an unmatched `}` is left for the parser to report, instead of
+                // leaving the lexer without a state to continue in.
+                if state.stack.len() > 1 {
+                    state.exit();
+                }
+
                 (TokenKind::RightBrace, b"}".into())
             }
             [b'(', ..] => {
@@ -812,16 +961,6 @@ impl Lexer {
                 } else {
                     let kind = identifier_to_keyword(&buffer).unwrap_or(TokenKind::Identifier);
 
-                    if kind == TokenKind::HaltCompiler {
-                        match state.source.read(3) {
-                            [b'(', b')', b';'] => {
-                                state.source.skip(3);
-                                state.replace(StackFrame::Halted);
-                            }
-                            _ => return Err(SyntaxError::InvalidHaltCompiler(state.source.span())),
-                        }
-                    }
-
                     (kind, buffer.into())
                 }
             }
@@ -1646,6 +1785,7 @@ impl Lexer {
     }
 
     fn tokenize_number(&self, state: &mut State) -> SyntaxResult<(TokenKind, ByteString)> {
+        let span = state.source.span();
         let mut buffer = Vec::new();
 
         let (base, kind) = match state.source.read(2) {
@@ -1673,9 +1813,9 @@ impl Lexer {
         };
 
         if kind != NumberKind::Float {
-            self.read_digits(state, &mut buffer, base);
+            self.read_digits(state, &mut buffer, base)?;
             if kind == NumberKind::Int {
-                return parse_int(&buffer);
+                return parse_int(&buffer, span);
             }
         }
 
@@ -1686,35 +1826,40 @@ impl Lexer {
         );
 
         if !is_float {
-            return parse_int(&buffer);
+            return parse_int(&buffer, span);
         }
 
         if let Some(b'.') = state.source.current() {
             buffer.push(b'.');
             state.source.next();
-            self.read_digits(state, &mut buffer, 10);
+            self.read_digits(state, &mut buffer, 10)?;
         }
 
-        if let Some(b'e' | b'E') = state.source.current() {
-            buffer.push(b'e');
+        if let Some(e @ (b'e' | b'E')) = state.source.current() {
+            buffer.push(*e);
             state.source.next();
             if let Some(b @ (b'-' | b'+')) = state.source.current() {
                 buffer.push(*b);
                 state.source.next();
             }
-            self.read_digits(state, &mut buffer, 10);
+            self.read_digits(state, &mut buffer, 10)?;
         }
 
         Ok((TokenKind::LiteralFloat, buffer.into()))
     }
 
-    fn read_digits(&self, state: &mut State, buffer: &mut Vec<u8>, base: usize) {
+    fn read_digits(
+        &self,
+        state: &mut State,
+        buffer: &mut Vec<u8>,
+        base: usize,
+    ) -> SyntaxResult<()> {
         if base == 16 {
-            self.read_digits_fn(state, buffer, u8::is_ascii_hexdigit);
+            self.read_digits_fn(state, buffer, u8::is_ascii_hexdigit)
         } else {
             let max = b'0' + base as u8;
-            self.read_digits_fn(state, buffer, |b| (b'0'..max).contains(b));
-        };
+            self.read_digits_fn(state, buffer, |b| (b'0'..max).contains(b))
+        }
     }
 
     fn read_digits_fn<F: Fn(&u8) -> bool>(
@@ -1722,13 +1867,15 @@ impl Lexer {
         state: &mut State,
         buffer: &mut Vec<u8>,
         is_digit: F,
-    ) {
+    ) -> SyntaxResult<()> {
         if let Some(b) = state.source.current() {
             if is_digit(b) {
                 state.source.next();
                 buffer.push(*b);
+            } else if b == &b'_' {
+                return Err(SyntaxError::InvalidNumericSeparator(state.source.span()));
             } else {
-                return;
+                return Ok(());
             }
         }
 
@@ -1738,27 +1885,112 @@ impl Lexer {
                     state.source.next();
                     buffer.push(*b);
                 }
+                // numeric separators are kept, so that the literal
+                // still matches the source code.
                 [b'_', b] if is_digit(b) => {
                     state.source.next();
                     state.source.next();
+                    buffer.push(b'_');
                     buffer.push(*b);
                 }
+                // a separator must be placed between two digits, e.g. `1__0` and `1_` are invalid.
+                [b'_', ..] => {
+                    return Err(SyntaxError::InvalidNumericSeparator(state.source.span()));
+                }
                 _ => {
-                    break;
+                    return Ok(());
+                }
+            }
+        }
+    }
+}
+
+/// A lazy iterator over the tokens of some input, created by [`Lexer::iter`].
+#[derive(Debug)]
+pub struct Tokens<'a> {
+    lexer: Lexer,
+    state: State<'a>,
+    buffer: Vec<Token>,
+    position: usize,
+    finished: bool,
+}
+
+impl<'a> Iterator for Tokens<'a> {
+    type Item = SyntaxResult<Token>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.position >= self.buffer.len() {
+            if self.finished {
+                return None;
+            }
+
+            self.buffer.clear();
+            self.position = 0;
+
+            match self.lexer.step(&mut self.state, &mut self.buffer) {
+                Ok(true) => {}
+                Ok(false) => {
+                    self.finished = true;
+                    self.buffer.push(Token {
+                        kind: TokenKind::Eof,
+                        span: self.state.source.span(),
+                        value: ByteString::default(),
+                    });
+                }
+                Err(error) => {
+                    self.finished = true;
+                    self.buffer.clear();
+
+                    return Some(Err(error));
                 }
             }
         }
+
+        let token = std::mem::take(&mut self.buffer[self.position]);
+        self.position += 1;
+
+        Some(Ok(token))
     }
 }
 
 // Parses an integer literal in the given base and converts errors to SyntaxError.
 // It returns a float token instead on overflow.
-fn parse_int(buffer: &[u8]) -> SyntaxResult<(TokenKind, ByteString)> {
-    Ok((TokenKind::LiteralInteger, buffer.into()))
+fn parse_int(buffer: &[u8], span: Span) -> SyntaxResult<(TokenKind, ByteString)> {
+    match decode_integer(buffer) {
+        Ok(_) => Ok((TokenKind::LiteralInteger, buffer.into())),
+        Err(error) if error.kind() == &IntErrorKind::PosOverflow => {
+            Ok((TokenKind::LiteralFloat, buffer.into()))
+        }
+        Err(_) if matches!(buffer, [b'0', b'0'..=b'9' | b'_', ..]) => {
+            Err(SyntaxError::InvalidOctalLiteral(span))
+        }
+        Err(_) => Err(SyntaxError::InvalidNumericLiteral(span)),
+    }
+}
+
+/// Decode the bytes of an integer literal, honouring the base given by its prefix.
+pub(crate) fn decode_integer(value: &[u8]) -> Result<i64, ParseIntError> {
+    let (digits, radix) = integer_digits(value);
+
+    // the digits of a literal produced by the lexer are always ascii.
+    let digits = String::from_utf8_lossy(digits).replace('_', "");
+
+    i64::from_str_radix(&digits, radix)
+}
+
+/// Split an integer literal into its digits and the base given by its prefix.
+pub(crate) fn integer_digits(value: &[u8]) -> (&[u8], u32) {
+    match value {
+        [b'0', b'x' | b'X', digits @ ..] => (digits, 16),
+        [b'0', b'o' | b'O', digits @ ..] => (digits, 8),
+        [b'0', b'b' | b'B', digits @ ..] => (digits, 2),
+        [b'0', digits @ ..] if !digits.is_empty() => (digits, 8),
+        digits => (digits, 10),
+    }
 }
 
 #[inline(always)]
-fn identifier_to_keyword(ident: &[u8]) -> Option<TokenKind> {
+pub(crate) fn identifier_to_keyword(ident: &[u8]) -> Option<TokenKind> {
     Some(match ident.to_ascii_lowercase().as_slice() {
         b"eval" => TokenKind::Eval,
         b"die" => TokenKind::Die,
@@ -1856,3 +2088,180 @@ enum NumberKind {
     IntOrFloat,
     OctalOrFloat,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lexer::trivia::TriviaKind;
+
+    fn string(source: &str) -> ByteString {
+        let tokens = Lexer::new()
+            .tokenize(format!("<?php {};", source).as_bytes())
+            .unwrap();
+
+        tokens[1].value.clone()
+    }
+
+    #[test]
+    fn test_single_quoted_string_escapes() {
+        assert_eq!(string(r"'\n'"), b"\\n");
+        assert_eq!(string(r"'\x41\$a\q'"), b"\\x41\\$a\\q");
+        assert_eq!(string(r"'\''"), b"'");
+        assert_eq!(string(r"'\\'"), b"\\");
+        assert_eq!(string(r"'\\n'"), b"\\n");
+    }
+
+    #[test]
+    fn test_double_quoted_string_escapes() {
+        assert_eq!(string(r#""\n""#), b"\n");
+        assert_eq!(string(r#""\x41\101\$a\q""#), b"AA$a\\q");
+        assert_eq!(string(r#""\"""#), b"\"");
+        assert_eq!(string(r#""\\""#), b"\\");
+        assert_eq!(string(r#""\u{1F600}""#), b"\xf0\x9f\x98\x80");
+    }
+
+    #[test]
+    fn test_iter_yields_tokens_lazily() {
+        let source = b"<?php echo \"Hello, {$name}\"; ?>world";
+        let mut tokens = Lexer::new().iter(source);
+
+        assert_eq!(
+            tokens.next().unwrap().unwrap().kind,
+            TokenKind::OpenTag(OpenTagKind::Full)
+        );
+
+        let rest = tokens.collect::<SyntaxResult<Vec<Token>>>().unwrap();
+        assert_eq!(rest.last().unwrap().kind, TokenKind::Eof);
+        assert_eq!(Lexer::new().tokenize(source).unwrap()[1..], rest[..]);
+    }
+
+    #[test]
+    fn test_iter_stops_after_error() {
+        let mut tokens = Lexer::new().iter(b"<?php echo 1; echo 0x_FF; echo 2;");
+
+        assert!(tokens.by_ref().take(5).all(|token| token.is_ok()));
+        assert!(tokens.next().unwrap().is_err());
+        assert!(tokens.next().is_none());
+    }
+
+    #[test]
+    fn test_boolean_and_is_lexed_as_a_single_token_kind() {
+        let tokens = Lexer::new().tokenize(b"<?php $a && $b;").unwrap();
+
+        assert_eq!(tokens[2].kind, TokenKind::BooleanAnd);
+        assert!(crate::parse("<?php $a && $b;").is_ok());
+    }
+
+    #[test]
+    fn test_tokenize_with_trivia_is_lossless() {
+        let source =
+            "<?php\n\n// greeting\necho 'Hello', \"\\x41 {$name}\"; # done\n/** doc */\nfoo(  );\n";
+        let tokens = Lexer::new().tokenize_with_trivia(source).unwrap();
+
+        let mut output = Vec::new();
+        for token in &tokens {
+            token
+                .leading_trivia
+                .iter()
+                .for_each(|trivia| output.extend(&trivia.value[..]));
+            output.extend(&token.text[..]);
+            token
+                .trailing_trivia
+                .iter()
+                .for_each(|trivia| output.extend(&trivia.value[..]));
+        }
+
+        assert_eq!(String::from_utf8(output).unwrap(), source);
+        assert_eq!(tokens.last().unwrap().token.kind, TokenKind::Eof);
+    }
+
+    #[test]
+    fn test_tokenize_with_trivia_attaches_comments() {
+        let tokens = Lexer::new()
+            .tokenize_with_trivia("<?php\n// leading\n$a = 1; // trailing\n/* next */ $b;")
+            .unwrap();
+
+        let variable = &tokens[1];
+        assert_eq!(variable.token.kind, TokenKind::Variable);
+        assert_eq!(
+            variable
+                .leading_trivia
+                .iter()
+                .map(|trivia| trivia.kind)
+                .collect::<Vec<_>>(),
+            [TriviaKind::SingleLineComment, TriviaKind::Whitespace]
+        );
+
+        let semicolon = &tokens[4];
+        assert_eq!(semicolon.token.kind, TokenKind::SemiColon);
+        assert_eq!(
+            semicolon
+                .trailing_trivia
+                .iter()
+                .map(|trivia| (trivia.kind, trivia.value.to_string()))
+                .collect::<Vec<_>>(),
+            [
+                (TriviaKind::Whitespace, " ".to_string()),
+                (TriviaKind::SingleLineComment, "// trailing".to_string()),
+                (TriviaKind::Whitespace, "\n".to_string()),
+            ]
+        );
+
+        let next = &tokens[5];
+        assert_eq!(next.leading_trivia[0].kind, TriviaKind::MultiLineComment);
+        assert_eq!(next.leading_trivia[0].span, Span::new(4, 1, 37));
+    }
+
+    #[test]
+    fn test_hash_mark_comments_and_attributes() {
+        let cases: [(&str, &[(TokenKind, &str)]); 7] = [
+            (
+                "#[Attr]",
+                &[
+                    (TokenKind::Attribute, "#["),
+                    (TokenKind::Identifier, "Attr"),
+                    (TokenKind::RightBracket, "]"),
+                ],
+            ),
+            ("#comment", &[(TokenKind::HashMarkComment, "#comment")]),
+            ("# comment", &[(TokenKind::HashMarkComment, "# comment")]),
+            ("#", &[(TokenKind::HashMarkComment, "#")]),
+            ("# [Attr]", &[(TokenKind::HashMarkComment, "# [Attr]")]),
+            (
+                "#comment\n#[Attr]",
+                &[
+                    (TokenKind::HashMarkComment, "#comment"),
+                    (TokenKind::Attribute, "#["),
+                    (TokenKind::Identifier, "Attr"),
+                    (TokenKind::RightBracket, "]"),
+                ],
+            ),
+            (
+                "#[Attr] # comment",
+                &[
+                    (TokenKind::Attribute, "#["),
+                    (TokenKind::Identifier, "Attr"),
+                    (TokenKind::RightBracket, "]"),
+                    (TokenKind::HashMarkComment, "# comment"),
+                ],
+            ),
+        ];
+
+        for (source, expected) in cases {
+            let tokens = Lexer::new()
+                .tokenize(format!("<?php {}", source).as_bytes())
+                .unwrap();
+
+            let actual = tokens[1..tokens.len() - 1]
+                .iter()
+                .map(|token| (token.kind.clone(), token.value.to_string()))
+                .collect::<Vec<_>>();
+            let expected = expected
+                .iter()
+                .map(|(kind, value)| (kind.clone(), value.to_string()))
+                .collect::<Vec<_>>();
+
+            assert_eq!(actual, expected, "lexing `{}`", source);
+        }
+    }
+}
@@ -63,6 +63,15 @@ pub struct TokenStream<'a> {
     cursor: usize,
 }
 
+/// A saved position in a token stream.
+///
+/// See [`TokenStream::checkpoint`] and [`TokenStream::restore`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Checkpoint {
+    cursor: usize,
+    comments: usize,
+}
+
 /// Token stream.
 impl<'a> TokenStream<'a> {
     pub fn new(tokens: &'a [Token]) -> TokenStream {
@@ -100,8 +109,21 @@ impl<'a> TokenStream<'a> {
     }
 
     /// Get previous token.
+    ///
+    /// All comments are skipped.
     pub const fn previous(&self) -> &'a Token {
-        let position = if self.cursor == 0 { 0 } else { self.cursor - 1 };
+        let mut position = if self.cursor == 0 { 0 } else { self.cursor - 1 };
+        while position > 0
+            && matches!(
+                self.tokens[position].kind,
+                TokenKind::SingleLineComment
+                    | TokenKind::MultiLineComment
+                    | TokenKind::HashMarkComment
+                    | TokenKind::DocumentComment
+            )
+        {
+            position -= 1;
+        }
 
         &self.tokens[position]
     }
@@ -154,6 +176,43 @@ impl<'a> TokenStream<'a> {
         }
     }
 
+    /// Save the current position in the stream.
+    ///
+    /// The stream can later be rewound to this position using
+    /// [`TokenStream::restore`], including any comments collected
+    /// since the checkpoint was created.
+    pub fn checkpoint(&self) -> Checkpoint {
+        Checkpoint {
+            cursor: self.cursor,
+            comments: self.comments.len(),
+        }
+    }
+
+    /// Rewind the stream to a previously saved position.
+    pub fn restore(&mut self, checkpoint: Checkpoint) {
+        self.cursor = checkpoint.cursor;
+
+        // pending comments are always the last comments before the cursor,
+        // so those taken since the checkpoint can be collected again.
+        let mut comments: Vec<&'a Token> = self.tokens[..self.cursor.min(self.length)]
+            .iter()
+            .rev()
+            .filter(|token| {
+                matches!(
+                    token.kind,
+                    TokenKind::SingleLineComment
+                        | TokenKind::MultiLineComment
+                        | TokenKind::HashMarkComment
+                        | TokenKind::DocumentComment
+                )
+            })
+            .take(checkpoint.comments)
+            .collect();
+        comments.reverse();
+
+        self.comments = comments;
+    }
+
     /// Check if current token is EOF.
     pub fn is_eof(&self) -> bool {
         if self.cursor >= self.length {
@@ -171,48 +230,20 @@ impl<'a> TokenStream<'a> {
         std::mem::swap(&mut self.comments, &mut comments);
 
         CommentGroup {
-            comments: comments
-                .iter()
-                .map(|token| match token {
-                    Token {
-                        kind: TokenKind::SingleLineComment,
-                        span,
-                        value,
-                    } => Comment {
-                        span: *span,
-                        format: CommentFormat::SingleLine,
-                        content: value.clone(),
-                    },
-                    Token {
-                        kind: TokenKind::MultiLineComment,
-                        span,
-                        value,
-                    } => Comment {
-                        span: *span,
-                        format: CommentFormat::MultiLine,
-                        content: value.clone(),
-                    },
-                    Token {
-                        kind: TokenKind::HashMarkComment,
-                        span,
-                        value,
-                    } => Comment {
-                        span: *span,
-                        format: CommentFormat::HashMark,
-                        content: value.clone(),
-                    },
-                    Token {
-                        kind: TokenKind::DocumentComment,
-                        span,
-                        value,
-                    } => Comment {
-                        span: *span,
-                        format: CommentFormat::Document,
-                        content: value.clone(),
-                    },
-                    _ => unreachable!(),
-                })
-                .collect(),
+            comments: comments.into_iter().map(comment).collect(),
+        }
+    }
+
+    /// Take the first pending comment, unless it is a document comment.
+    ///
+    /// Document comments are left in place, so that they are still
+    /// attached to the declaration that follows them.
+    pub fn take_comment(&mut self) -> Option<Comment> {
+        match self.comments.first() {
+            Some(token) if token.kind != TokenKind::DocumentComment => {
+                Some(comment(self.comments.remove(0)))
+            }
+            _ => None,
         }
     }
 
@@ -251,3 +282,45 @@ impl<'a> From<&'a Vec<Token>> for TokenStream<'a> {
         Self::new(tokens.as_slice())
     }
 }
+
+fn comment(token: &Token) -> Comment {
+    match token {
+        Token {
+            kind: TokenKind::SingleLineComment,
+            span,
+            value,
+        } => Comment {
+            span: *span,
+            format: CommentFormat::SingleLine,
+            content: value.clone(),
+        },
+        Token {
+            kind: TokenKind::MultiLineComment,
+            span,
+            value,
+        } => Comment {
+            span: *span,
+            format: CommentFormat::MultiLine,
+            content: value.clone(),
+        },
+        Token {
+            kind: TokenKind::HashMarkComment,
+            span,
+            value,
+        } => Comment {
+            span: *span,
+            format: CommentFormat::HashMark,
+            content: value.clone(),
+        },
+        Token {
+            kind: TokenKind::DocumentComment,
+            span,
+            value,
+        } => Comment {
+            span: *span,
+            format: CommentFormat::Document,
+            content: value.clone(),
+        },
+        _ => unreachable!(),
+    }
+}
@@ -89,8 +89,6 @@ pub enum TokenKind {
     Abstract,
     Ampersand,
     AmpersandEquals,
-    And,
-    AndEquals,
     Array,
     ArrayCast,
     Arrow,
@@ -295,8 +293,8 @@ impl Display for TokenKind {
             Self::Self_ => "self",
             Self::Parent => "parent",
             Self::Backtick => "`",
-            Self::StartDocString(_) => todo!(),
-            Self::EndDocString(..) => todo!(),
+            Self::StartDocString(_) => "<<<",
+            Self::EndDocString(..) => return write!(f, "end of doc string"),
             Self::BangEquals => "!=",
             Self::From => "from",
             Self::Print => "print",
@@ -309,8 +307,6 @@ impl Display for TokenKind {
             Self::UnsetCast => "(unset)",
             Self::Abstract => "abstract",
             Self::Ampersand => "&",
-            Self::And => "&&",
-            Self::AndEquals => "&=",
             Self::Arrow => "->",
             Self::QuestionArrow => "?->",
             Self::Array => "array",
@@ -384,7 +380,7 @@ impl Display for TokenKind {
             Self::If => "if",
             Self::Implements => "implements",
             Self::Increment => "++",
-            Self::InlineHtml => "InlineHtml",
+            Self::InlineHtml => return write!(f, "inline html"),
             Self::LiteralInteger => return write!(f, "integer literal"),
             Self::LeftBrace => "{",
             Self::LeftBracket => "[",
@@ -449,7 +445,7 @@ impl Display for TokenKind {
             Self::LogicalXor => "xor",
             Self::Foreach => "foreach",
             Self::AmpersandEquals => "&=",
-            Self::At => "at",
+            Self::At => "@",
             Self::BangDoubleEquals => "!==",
             Self::TraitConstant => "__TRAIT__",
             Self::FunctionConstant => "__FUNCTION__",
@@ -488,3 +484,44 @@ impl Display for TokenKind {
         write!(f, "{}", s)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lexer::Lexer;
+
+    #[test]
+    fn test_display_renders_source_text() {
+        assert_eq!(TokenKind::At.to_string(), "@");
+        assert_eq!(TokenKind::Spaceship.to_string(), "<=>");
+        assert_eq!(
+            TokenKind::StartDocString(DocStringKind::Heredoc).to_string(),
+            "<<<"
+        );
+        assert_eq!(TokenKind::InlineHtml.to_string(), "inline html");
+    }
+
+    #[test]
+    fn test_display_round_trips_through_the_lexer() {
+        let kinds = [
+            TokenKind::At,
+            TokenKind::Bang,
+            TokenKind::BangDoubleEquals,
+            TokenKind::DoubleQuestionEquals,
+            TokenKind::Ellipsis,
+            TokenKind::PowEquals,
+            TokenKind::QuestionArrow,
+            TokenKind::Spaceship,
+            TokenKind::Instanceof,
+            TokenKind::LogicalXor,
+        ];
+
+        for kind in kinds {
+            let tokens = Lexer::new()
+                .tokenize(format!("<?php {}", kind).as_bytes())
+                .unwrap();
+
+            assert_eq!(tokens[1].kind, kind);
+        }
+    }
+}
@@ -0,0 +1,166 @@
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::lexer::byte_string::ByteString;
+use crate::lexer::token::Span;
+use crate::lexer::token::Token;
+use crate::lexer::token::TokenKind;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Deserialize, Serialize, JsonSchema)]
+
+pub enum TriviaKind {
+    Whitespace,
+    SingleLineComment,
+    HashMarkComment,
+    MultiLineComment,
+    DocumentComment,
+}
+
+/// A piece of source code that carries no meaning for the parser, such as
+/// whitespace or a comment.
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+
+pub struct Trivia {
+    pub kind: TriviaKind,
+    pub span: Span,
+    pub value: ByteString,
+}
+
+/// A token along with the trivia surrounding it, created by [`crate::lexer::Lexer::tokenize_with_trivia`].
+///
+/// Trailing trivia runs up to and including the end of the token's line, and everything
+/// else belongs to the leading trivia of the following token, so concatenating the
+/// leading trivia, text and trailing trivia of every token reproduces the input.
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+
+pub struct TokenWithTrivia {
+    pub token: Token,
+    /// The token exactly as it was written, which may differ from the token's value,
+    /// e.g. the value of a string literal does not include its quotes.
+    pub text: ByteString,
+    pub leading_trivia: Vec<Trivia>,
+    pub trailing_trivia: Vec<Trivia>,
+}
+
+enum Piece {
+    Token(Token, ByteString),
+    Trivia(Trivia),
+}
+
+/// Attach trivia to the given tokens, where each token is paired with the
+/// position in `input` at which the lexer finished consuming it.
+pub(crate) fn attach(input: &[u8], tokens: Vec<(Token, usize)>) -> Vec<TokenWithTrivia> {
+    let mut pieces = Vec::with_capacity(tokens.len() * 2);
+    let mut cursor = Span::new(1, 1, 0);
+
+    for (token, end) in tokens {
+        whitespace(
+            input,
+            &mut cursor,
+            token.span.position.min(input.len()),
+            &mut pieces,
+        );
+
+        // some tokens consume whitespace beyond their text, e.g. a single line comment
+        // consumes the newline that ends it, in which case the rest is trivia.
+        let mut text = &input[cursor.position..end.max(cursor.position)];
+        if let Some(rest) = text.strip_prefix(&token.value[..]) {
+            if rest.iter().all(|byte| byte.is_ascii_whitespace()) {
+                text = &text[..token.value.len()];
+            }
+        }
+
+        let until = cursor.position + text.len();
+        advance(input, &mut cursor, until);
+
+        let kind = match token.kind {
+            TokenKind::SingleLineComment => Some(TriviaKind::SingleLineComment),
+            TokenKind::HashMarkComment => Some(TriviaKind::HashMarkComment),
+            TokenKind::MultiLineComment => Some(TriviaKind::MultiLineComment),
+            TokenKind::DocumentComment => Some(TriviaKind::DocumentComment),
+            _ => None,
+        };
+
+        pieces.push(match kind {
+            Some(kind) => Piece::Trivia(Trivia {
+                kind,
+                span: token.span,
+                value: token.value,
+            }),
+            None => Piece::Token(token, text.into()),
+        });
+
+        whitespace(input, &mut cursor, end, &mut pieces);
+    }
+
+    let mut tokens = Vec::new();
+    let mut leading = Vec::new();
+    let mut pieces = pieces.into_iter().peekable();
+
+    while let Some(piece) = pieces.next() {
+        let (token, text) = match piece {
+            Piece::Trivia(trivia) => {
+                leading.push(trivia);
+                continue;
+            }
+            Piece::Token(token, text) => (token, text),
+        };
+
+        let mut trailing = Vec::new();
+        while token.kind != TokenKind::Eof && matches!(pieces.peek(), Some(Piece::Trivia(_))) {
+            if let Some(Piece::Trivia(trivia)) = pieces.next() {
+                let newline = trivia.value.ends_with(b"\n");
+
+                trailing.push(trivia);
+
+                if newline {
+                    break;
+                }
+            }
+        }
+
+        tokens.push(TokenWithTrivia {
+            token,
+            text,
+            leading_trivia: std::mem::take(&mut leading),
+            trailing_trivia: trailing,
+        });
+    }
+
+    tokens
+}
+
+/// Push the whitespace between the cursor and `until` as trivia, split into one
+/// piece per line so that a token's trailing trivia can stop at the end of its line.
+fn whitespace(input: &[u8], cursor: &mut Span, until: usize, pieces: &mut Vec<Piece>) {
+    while cursor.position < until {
+        let span = *cursor;
+        let rest = &input[span.position..until];
+        let length = match rest.iter().position(|byte| *byte == b'\n') {
+            Some(newline) => newline + 1,
+            None => rest.len(),
+        };
+
+        advance(input, cursor, span.position + length);
+
+        pieces.push(Piece::Trivia(Trivia {
+            kind: TriviaKind::Whitespace,
+            span,
+            value: rest[..length].into(),
+        }));
+    }
+}
+
+fn advance(input: &[u8], cursor: &mut Span, until: usize) {
+    while cursor.position < until {
+        if input[cursor.position] == b'\n' {
+            cursor.line += 1;
+            cursor.column = 1;
+        } else {
+            cursor.column += 1;
+        }
+
+        cursor.position += 1;
+    }
+}
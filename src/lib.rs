@@ -3,7 +3,14 @@ pub mod lexer;
 pub mod node;
 pub mod parser;
 pub mod printer;
+pub mod resolver;
 pub mod traverser;
 
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
 pub use lexer::stream::TokenStream;
-pub use parser::{construct, parse};
+pub use parser::config::ParserConfig;
+pub use parser::config::PhpVersion;
+pub use parser::{construct, construct_with_config, parse, parse_with_config};
+pub use parser::{Associativity, Precedence};
@@ -4,4 +4,9 @@ pub trait Node: Any {
     fn children(&mut self) -> Vec<&mut dyn Node> {
         vec![]
     }
+
+    /// The same children as [`Node::children`], borrowed immutably.
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        vec![]
+    }
 }
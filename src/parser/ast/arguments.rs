@@ -21,6 +21,10 @@ impl Node for PositionalArgument {
     fn children(&mut self) -> Vec<&mut dyn Node> {
         vec![&mut self.value]
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        vec![&self.value]
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -36,6 +40,10 @@ impl Node for NamedArgument {
     fn children(&mut self) -> Vec<&mut dyn Node> {
         vec![&mut self.name, &mut self.value]
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        vec![&self.name, &self.value]
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -45,6 +53,15 @@ pub enum Argument {
     Named(NamedArgument),
 }
 
+impl Argument {
+    pub fn is_spread(&self) -> bool {
+        match self {
+            Argument::Positional(argument) => argument.ellipsis.is_some(),
+            Argument::Named(argument) => argument.ellipsis.is_some(),
+        }
+    }
+}
+
 impl Node for Argument {
     fn children(&mut self) -> Vec<&mut dyn Node> {
         match self {
@@ -52,6 +69,13 @@ impl Node for Argument {
             Argument::Named(argument) => vec![argument],
         }
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        match self {
+            Argument::Positional(argument) => vec![argument],
+            Argument::Named(argument) => vec![argument],
+        }
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -85,6 +109,10 @@ impl Node for ArgumentList {
             .map(|a| a as &mut dyn Node)
             .collect()
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        self.arguments.iter().map(|a| a as &dyn Node).collect()
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -100,6 +128,10 @@ impl Node for SingleArgument {
     fn children(&mut self) -> Vec<&mut dyn Node> {
         vec![&mut self.argument]
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        vec![&self.argument]
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -7,11 +7,13 @@ use serde::Serialize;
 use crate::lexer::token::Span;
 use crate::node::Node;
 use crate::parser::ast::attributes::AttributeGroup;
+use crate::parser::ast::comments::CommentGroup;
 use crate::parser::ast::constant::ClassishConstant;
 use crate::parser::ast::functions::AbstractConstructor;
 use crate::parser::ast::functions::AbstractMethod;
 use crate::parser::ast::functions::ConcreteConstructor;
 use crate::parser::ast::functions::ConcreteMethod;
+use crate::parser::ast::identifiers::Name;
 use crate::parser::ast::identifiers::SimpleIdentifier;
 use crate::parser::ast::modifiers::ClassModifierGroup;
 use crate::parser::ast::properties::Property;
@@ -49,11 +51,19 @@ impl Node for ClassBody {
             .map(|member| member as &mut dyn Node)
             .collect()
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        self.members
+            .iter()
+            .map(|member| member as &dyn Node)
+            .collect()
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
 
 pub struct ClassStatement {
+    pub comments: CommentGroup,
     pub attributes: Vec<AttributeGroup>, // `#[Qux]`
     #[serde(flatten)]
     pub modifiers: ClassModifierGroup, // `abstract`, `final`
@@ -76,6 +86,18 @@ impl Node for ClassStatement {
         children.push(&mut self.body);
         children
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        let mut children: Vec<&dyn Node> = vec![&self.name];
+        if let Some(extends) = &self.extends {
+            children.push(extends);
+        }
+        if let Some(implements) = &self.implements {
+            children.push(implements);
+        }
+        children.push(&self.body);
+        children
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -108,6 +130,13 @@ impl Node for AnonymousClassBody {
             .map(|member| member as &mut dyn Node)
             .collect()
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        self.members
+            .iter()
+            .map(|member| member as &dyn Node)
+            .collect()
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -132,36 +161,52 @@ impl Node for AnonymousClassExpression {
         children.push(&mut self.body);
         children
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        let mut children: Vec<&dyn Node> = vec![];
+        if let Some(extends) = &self.extends {
+            children.push(extends);
+        }
+        if let Some(implements) = &self.implements {
+            children.push(implements);
+        }
+        children.push(&self.body);
+        children
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
 
 pub struct ClassExtends {
-    pub extends: Span,            // `extends`
-    pub parent: SimpleIdentifier, // `Foo`
+    pub extends: Span, // `extends`
+    pub parent: Name,  // `Foo`
 }
 
 impl Node for ClassExtends {
     fn children(&mut self) -> Vec<&mut dyn Node> {
         vec![&mut self.parent]
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        vec![&self.parent]
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
 
 pub struct ClassImplements {
-    pub implements: Span,                             // `implements`
-    pub interfaces: CommaSeparated<SimpleIdentifier>, // `Bar, Baz`
+    pub implements: Span,                 // `implements`
+    pub interfaces: CommaSeparated<Name>, // `Bar, Baz`
 }
 
 impl ClassImplements {
-    pub fn iter(&self) -> Iter<'_, SimpleIdentifier> {
+    pub fn iter(&self) -> Iter<'_, Name> {
         self.interfaces.iter()
     }
 }
 
 impl IntoIterator for ClassImplements {
-    type Item = SimpleIdentifier;
+    type Item = Name;
     type IntoIter = std::vec::IntoIter<Self::Item>;
 
     fn into_iter(self) -> Self::IntoIter {
@@ -173,6 +218,10 @@ impl Node for ClassImplements {
     fn children(&mut self) -> Vec<&mut dyn Node> {
         self.interfaces.children()
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        self.interfaces.children_ref()
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -201,6 +250,19 @@ impl Node for ClassMember {
             ClassMember::ConcreteConstructor(method) => vec![method],
         }
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        match self {
+            ClassMember::Constant(constant) => vec![constant],
+            ClassMember::TraitUsage(usage) => vec![usage],
+            ClassMember::Property(property) => vec![property],
+            ClassMember::VariableProperty(property) => vec![property],
+            ClassMember::AbstractMethod(method) => vec![method],
+            ClassMember::AbstractConstructor(method) => vec![method],
+            ClassMember::ConcreteMethod(method) => vec![method],
+            ClassMember::ConcreteConstructor(method) => vec![method],
+        }
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -225,4 +287,15 @@ impl Node for AnonymousClassMember {
             AnonymousClassMember::ConcreteConstructor(method) => vec![method],
         }
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        match self {
+            AnonymousClassMember::Constant(constant) => vec![constant],
+            AnonymousClassMember::TraitUsage(usage) => vec![usage],
+            AnonymousClassMember::Property(property) => vec![property],
+            AnonymousClassMember::VariableProperty(property) => vec![property],
+            AnonymousClassMember::ConcreteMethod(method) => vec![method],
+            AnonymousClassMember::ConcreteConstructor(method) => vec![method],
+        }
+    }
 }
@@ -37,6 +37,14 @@ impl CommentGroup {
     pub fn iter(&self) -> Iter<'_, Comment> {
         self.comments.iter()
     }
+
+    /// Get the document comment (`/** ... */`) nearest to the commented node, if any.
+    pub fn doc_comment(&self) -> Option<&Comment> {
+        self.comments
+            .iter()
+            .rev()
+            .find(|comment| comment.format == CommentFormat::Document)
+    }
 }
 
 impl IntoIterator for CommentGroup {
@@ -8,8 +8,10 @@ use crate::lexer::token::Span;
 use crate::node::Node;
 use crate::parser::ast::attributes::AttributeGroup;
 use crate::parser::ast::comments::CommentGroup;
+use crate::parser::ast::data_type::Type;
 use crate::parser::ast::identifiers::SimpleIdentifier;
 use crate::parser::ast::modifiers::ConstantModifierGroup;
+use crate::parser::ast::Ending;
 use crate::parser::ast::Expression;
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -24,6 +26,10 @@ impl Node for ConstantEntry {
     fn children(&mut self) -> Vec<&mut dyn Node> {
         vec![&mut self.name, &mut self.value]
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        vec![&self.name, &self.value]
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -32,7 +38,7 @@ pub struct ConstantStatement {
     pub comments: CommentGroup,
     pub r#const: Span,               // `const`
     pub entries: Vec<ConstantEntry>, // `FOO = 123`
-    pub semicolon: Span,             // `;`
+    pub ending: Ending,              // `;` or `?>`
 }
 
 impl ConstantStatement {
@@ -57,6 +63,10 @@ impl Node for ConstantStatement {
             .map(|e| e as &mut dyn Node)
             .collect()
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        self.entries.iter().map(|e| e as &dyn Node).collect()
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -66,6 +76,7 @@ pub struct ClassishConstant {
     pub attributes: Vec<AttributeGroup>,  // `#[Foo]`
     pub modifiers: ConstantModifierGroup, // `public`
     pub r#const: Span,                    // `const`
+    pub r#type: Option<Type>,             // `int`
     #[serde(flatten)]
     pub entries: Vec<ConstantEntry>, // `FOO = 123`
     pub semicolon: Span,                  // `;`
@@ -88,9 +99,20 @@ impl IntoIterator for ClassishConstant {
 
 impl Node for ClassishConstant {
     fn children(&mut self) -> Vec<&mut dyn Node> {
-        self.entries
-            .iter_mut()
-            .map(|e| e as &mut dyn Node)
-            .collect()
+        let mut children: Vec<&mut dyn Node> = vec![];
+        if let Some(r#type) = &mut self.r#type {
+            children.push(r#type);
+        }
+        children.extend(self.entries.iter_mut().map(|e| e as &mut dyn Node));
+        children
+    }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        let mut children: Vec<&dyn Node> = vec![];
+        if let Some(r#type) = &self.r#type {
+            children.push(r#type);
+        }
+        children.extend(self.entries.iter().map(|e| e as &dyn Node));
+        children
     }
 }
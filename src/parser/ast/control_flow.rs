@@ -22,6 +22,10 @@ impl Node for IfStatement {
     fn children(&mut self) -> Vec<&mut dyn Node> {
         vec![&mut self.condition, &mut self.body]
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        vec![&self.condition, &self.body]
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -82,6 +86,42 @@ impl Node for IfStatementBody {
             }
         }
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        match self {
+            IfStatementBody::Statement {
+                statement,
+                elseifs,
+                r#else,
+            } => {
+                let mut children: Vec<&dyn Node> = vec![statement.as_ref()];
+                children.extend(
+                    elseifs
+                        .iter()
+                        .map(|elseif| elseif as &dyn Node)
+                        .collect::<Vec<&dyn Node>>(),
+                );
+                if let Some(r#else) = r#else {
+                    children.push(r#else as &dyn Node);
+                }
+                children
+            }
+            IfStatementBody::Block {
+                statements,
+                elseifs,
+                r#else,
+                ..
+            } => {
+                let mut children: Vec<&dyn Node> = vec![];
+                children.extend(statements.iter().map(|statement| statement as &dyn Node));
+                children.extend(elseifs.iter().map(|elseif| elseif as &dyn Node));
+                if let Some(r#else) = r#else {
+                    children.push(r#else as &dyn Node);
+                }
+                children
+            }
+        }
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -98,6 +138,10 @@ impl Node for IfStatementElseIf {
     fn children(&mut self) -> Vec<&mut dyn Node> {
         vec![&mut self.condition, self.statement.as_mut()]
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        vec![&self.condition, self.statement.as_ref()]
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -111,6 +155,10 @@ impl Node for IfStatementElse {
     fn children(&mut self) -> Vec<&mut dyn Node> {
         vec![self.statement.as_mut()]
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        vec![self.statement.as_ref()]
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -134,6 +182,16 @@ impl Node for IfStatementElseIfBlock {
         );
         children
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        let mut children: Vec<&dyn Node> = vec![&self.condition];
+        children.extend(
+            self.statements
+                .iter()
+                .map(|statement| statement as &dyn Node),
+        );
+        children
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -151,4 +209,11 @@ impl Node for IfStatementElseBlock {
             .map(|statement| statement as &mut dyn Node)
             .collect()
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        self.statements
+            .iter()
+            .map(|statement| statement as &dyn Node)
+            .collect()
+    }
 }
@@ -150,4 +150,13 @@ impl Node for Type {
             _ => vec![],
         }
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        match self {
+            Type::Nullable(_, t) => vec![t.as_ref() as &dyn Node],
+            Type::Union(ts) => ts.iter().map(|x| x as &dyn Node).collect(),
+            Type::Intersection(ts) => ts.iter().map(|x| x as &dyn Node).collect(),
+            _ => vec![],
+        }
+    }
 }
@@ -6,6 +6,7 @@ use crate::lexer::token::Span;
 use crate::node::Node;
 use crate::parser::ast::identifiers::SimpleIdentifier;
 use crate::parser::ast::literals::Literal;
+use crate::parser::ast::Ending;
 use crate::parser::ast::Expression;
 use crate::parser::ast::Statement;
 
@@ -21,6 +22,10 @@ impl Node for DeclareEntry {
     fn children(&mut self) -> Vec<&mut dyn Node> {
         vec![&mut self.key, &mut self.value]
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        vec![&self.key, &self.value]
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -38,14 +43,18 @@ impl Node for DeclareEntryGroup {
             .map(|e| e as &mut dyn Node)
             .collect()
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        self.entries.iter().map(|e| e as &dyn Node).collect()
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(tag = "type", content = "value")]
 pub enum DeclareBody {
-    // declaration is terminated with `;`
+    // declaration is terminated with `;` or `?>`
     Noop {
-        semicolon: Span, // `;`
+        ending: Ending, // `;` or `?>`
     },
     // declaration is followed by a `{` and terminated with `}` after multiple statements.
     Braced {
@@ -53,16 +62,16 @@ pub enum DeclareBody {
         statements: Vec<Statement>, // `*statements*`
         right_brace: Span,          // `}`
     },
-    // declaration is terminated with `;` after a single expression.
+    // declaration is terminated with `;` or `?>` after a single expression.
     Expression {
         expression: Expression, // `*expression*`
-        semicolon: Span,        // `;`
+        ending: Ending,         // `;` or `?>`
     },
-    // declaration is followed by a `:` and terminated with `enddeclare` and `;` after multiple statements.
+    // declaration is followed by a `:` and terminated with `enddeclare` and `;` or `?>` after multiple statements.
     Block {
         colon: Span,                // `:`
         statements: Vec<Statement>, // `*statements*`
-        end: (Span, Span),          // `enddeclare` + `;`
+        end: (Span, Ending),        // `enddeclare` + `;` or `?>`
     },
 }
 
@@ -79,6 +88,19 @@ impl Node for DeclareBody {
             }
         }
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        match self {
+            DeclareBody::Noop { .. } => vec![],
+            DeclareBody::Braced { statements, .. } => {
+                statements.iter().map(|s| s as &dyn Node).collect()
+            }
+            DeclareBody::Expression { expression, .. } => vec![expression],
+            DeclareBody::Block { statements, .. } => {
+                statements.iter().map(|s| s as &dyn Node).collect()
+            }
+        }
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -93,4 +115,8 @@ impl Node for DeclareStatement {
     fn children(&mut self) -> Vec<&mut dyn Node> {
         vec![&mut self.entries, &mut self.body]
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        vec![&self.entries, &self.body]
+    }
 }
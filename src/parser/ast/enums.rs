@@ -5,8 +5,10 @@ use serde::Serialize;
 use crate::lexer::token::Span;
 use crate::node::Node;
 use crate::parser::ast::attributes::AttributeGroup;
+use crate::parser::ast::comments::CommentGroup;
 use crate::parser::ast::constant::ClassishConstant;
 use crate::parser::ast::functions::ConcreteMethod;
+use crate::parser::ast::identifiers::Name;
 use crate::parser::ast::identifiers::SimpleIdentifier;
 use crate::parser::ast::Expression;
 
@@ -25,6 +27,10 @@ impl Node for UnitEnumCase {
     fn children(&mut self) -> Vec<&mut dyn Node> {
         vec![&mut self.name]
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        vec![&self.name]
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -45,6 +51,15 @@ impl Node for UnitEnumMember {
             UnitEnumMember::TraitUsage(trait_usage) => vec![trait_usage],
         }
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        match self {
+            UnitEnumMember::Case(case) => vec![case],
+            UnitEnumMember::Method(method) => vec![method],
+            UnitEnumMember::Constant(constant) => vec![constant],
+            UnitEnumMember::TraitUsage(trait_usage) => vec![trait_usage],
+        }
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -62,16 +77,21 @@ impl Node for UnitEnumBody {
             .map(|m| m as &mut dyn Node)
             .collect()
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        self.members.iter().map(|m| m as &dyn Node).collect()
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
 
 pub struct UnitEnumStatement {
-    pub attributes: Vec<AttributeGroup>,   // `#[Foo]`
-    pub r#enum: Span,                      // `enum`
-    pub name: SimpleIdentifier,            // `Foo`
-    pub implements: Vec<SimpleIdentifier>, // `implements Bar`
-    pub body: UnitEnumBody,                // `{ ... }`
+    pub comments: CommentGroup,
+    pub attributes: Vec<AttributeGroup>, // `#[Foo]`
+    pub r#enum: Span,                    // `enum`
+    pub name: SimpleIdentifier,          // `Foo`
+    pub implements: Vec<Name>,           // `implements Bar`
+    pub body: UnitEnumBody,              // `{ ... }`
 }
 
 impl Node for UnitEnumStatement {
@@ -83,6 +103,15 @@ impl Node for UnitEnumStatement {
         children.push(&mut self.body);
         children
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        let mut children: Vec<&dyn Node> = vec![&self.name];
+        for implement in &self.implements {
+            children.push(implement);
+        }
+        children.push(&self.body);
+        children
+    }
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize, JsonSchema)]
@@ -111,6 +140,10 @@ impl Node for BackedEnumCase {
     fn children(&mut self) -> Vec<&mut dyn Node> {
         vec![&mut self.name, &mut self.value]
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        vec![&self.name, &self.value]
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -131,6 +164,15 @@ impl Node for BackedEnumMember {
             BackedEnumMember::TraitUsage(trait_usage) => vec![trait_usage],
         }
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        match self {
+            BackedEnumMember::Case(case) => vec![case],
+            BackedEnumMember::Method(method) => vec![method],
+            BackedEnumMember::Constant(constant) => vec![constant],
+            BackedEnumMember::TraitUsage(trait_usage) => vec![trait_usage],
+        }
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -148,17 +190,22 @@ impl Node for BackedEnumBody {
             .map(|m| m as &mut dyn Node)
             .collect()
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        self.members.iter().map(|m| m as &dyn Node).collect()
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
 
 pub struct BackedEnumStatement {
-    pub attributes: Vec<AttributeGroup>,   // `#[Foo]`
-    pub r#enum: Span,                      // `enum`
-    pub name: SimpleIdentifier,            // `Foo`
-    pub backed_type: BackedEnumType,       // `: string`
-    pub implements: Vec<SimpleIdentifier>, // `implements Bar`
-    pub body: BackedEnumBody,              // `{ ... }`
+    pub comments: CommentGroup,
+    pub attributes: Vec<AttributeGroup>, // `#[Foo]`
+    pub r#enum: Span,                    // `enum`
+    pub name: SimpleIdentifier,          // `Foo`
+    pub backed_type: BackedEnumType,     // `: string`
+    pub implements: Vec<Name>,           // `implements Bar`
+    pub body: BackedEnumBody,            // `{ ... }`
 }
 
 impl Node for BackedEnumStatement {
@@ -170,4 +217,13 @@ impl Node for BackedEnumStatement {
         children.push(&mut self.body);
         children
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        let mut children: Vec<&dyn Node> = vec![&self.name, &self.backed_type];
+        for implement in &self.implements {
+            children.push(implement);
+        }
+        children.push(&self.body);
+        children
+    }
 }
@@ -28,6 +28,10 @@ impl Node for ReturnType {
     fn children(&mut self) -> Vec<&mut dyn Node> {
         vec![&mut self.data_type]
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        vec![&self.data_type]
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -42,6 +46,16 @@ pub struct FunctionParameter {
     pub ampersand: Option<Span>,
 }
 
+impl FunctionParameter {
+    pub fn is_variadic(&self) -> bool {
+        self.ellipsis.is_some()
+    }
+
+    pub fn is_reference(&self) -> bool {
+        self.ampersand.is_some()
+    }
+}
+
 impl Node for FunctionParameter {
     fn children(&mut self) -> Vec<&mut dyn Node> {
         let mut children: Vec<&mut dyn Node> = vec![&mut self.name];
@@ -53,6 +67,17 @@ impl Node for FunctionParameter {
         }
         children
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        let mut children: Vec<&dyn Node> = vec![&self.name];
+        if let Some(data_type) = &self.data_type {
+            children.push(data_type);
+        }
+        if let Some(default) = &self.default {
+            children.push(default);
+        }
+        children
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -83,6 +108,10 @@ impl Node for FunctionParameterList {
     fn children(&mut self) -> Vec<&mut dyn Node> {
         self.parameters.children()
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        self.parameters.children_ref()
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -101,6 +130,10 @@ impl Node for FunctionBody {
             .map(|x| x as &mut dyn Node)
             .collect()
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        self.statements.iter().map(|x| x as &dyn Node).collect()
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -125,6 +158,14 @@ impl Node for FunctionStatement {
         }
         children
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        let mut children: Vec<&dyn Node> = vec![&self.name, &self.parameters, &self.body];
+        if let Some(return_type) = &self.return_type {
+            children.push(return_type);
+        }
+        children
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -135,10 +176,20 @@ pub struct ClosureUseVariable {
     pub variable: SimpleVariable,
 }
 
+impl ClosureUseVariable {
+    pub fn is_reference(&self) -> bool {
+        self.ampersand.is_some()
+    }
+}
+
 impl Node for ClosureUseVariable {
     fn children(&mut self) -> Vec<&mut dyn Node> {
         vec![&mut self.variable]
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        vec![&self.variable]
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -155,6 +206,10 @@ impl Node for ClosureUse {
     fn children(&mut self) -> Vec<&mut dyn Node> {
         self.variables.children()
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        self.variables.children_ref()
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -183,6 +238,18 @@ impl Node for ClosureExpression {
         children.push(&mut self.body);
         children
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        let mut children: Vec<&dyn Node> = vec![&self.parameters];
+        if let Some(uses) = &self.uses {
+            children.push(uses);
+        }
+        if let Some(return_type) = &self.return_type {
+            children.push(return_type);
+        }
+        children.push(&self.body);
+        children
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -208,6 +275,15 @@ impl Node for ArrowFunctionExpression {
         children.push(self.body.as_mut());
         children
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        let mut children: Vec<&dyn Node> = vec![&self.parameters];
+        if let Some(return_type) = &self.return_type {
+            children.push(return_type);
+        }
+        children.push(self.body.as_ref());
+        children
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -224,6 +300,22 @@ pub struct ConstructorParameter {
     pub modifiers: PromotedPropertyModifierGroup,
 }
 
+impl ConstructorParameter {
+    /// Whether this parameter is promoted to a property, i.e. it has a
+    /// visibility or `readonly` modifier.
+    pub fn is_promoted(&self) -> bool {
+        !self.modifiers.is_empty()
+    }
+
+    pub fn is_variadic(&self) -> bool {
+        self.ellipsis.is_some()
+    }
+
+    pub fn is_reference(&self) -> bool {
+        self.ampersand.is_some()
+    }
+}
+
 impl Node for ConstructorParameter {
     fn children(&mut self) -> Vec<&mut dyn Node> {
         let mut children: Vec<&mut dyn Node> = vec![&mut self.name];
@@ -235,6 +327,17 @@ impl Node for ConstructorParameter {
         }
         children
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        let mut children: Vec<&dyn Node> = vec![&self.name];
+        if let Some(data_type) = &self.data_type {
+            children.push(data_type);
+        }
+        if let Some(default) = &self.default {
+            children.push(default);
+        }
+        children
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -246,10 +349,25 @@ pub struct ConstructorParameterList {
     pub right_parenthesis: Span,
 }
 
+impl ConstructorParameterList {
+    pub fn iter(&self) -> Iter<'_, ConstructorParameter> {
+        self.parameters.iter()
+    }
+
+    /// Iterate over the parameters that are promoted to properties.
+    pub fn promoted(&self) -> impl Iterator<Item = &ConstructorParameter> {
+        self.iter().filter(|parameter| parameter.is_promoted())
+    }
+}
+
 impl Node for ConstructorParameterList {
     fn children(&mut self) -> Vec<&mut dyn Node> {
         self.parameters.children()
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        self.parameters.children_ref()
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -272,6 +390,10 @@ impl Node for AbstractConstructor {
     fn children(&mut self) -> Vec<&mut dyn Node> {
         vec![&mut self.name, &mut self.parameters]
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        vec![&self.name, &self.parameters]
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -294,6 +416,10 @@ impl Node for ConcreteConstructor {
     fn children(&mut self) -> Vec<&mut dyn Node> {
         vec![&mut self.name, &mut self.parameters, &mut self.body]
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        vec![&self.name, &self.parameters, &self.body]
+    }
 }
 
 impl ConcreteConstructor {
@@ -337,6 +463,14 @@ impl Node for AbstractMethod {
         }
         children
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        let mut children: Vec<&dyn Node> = vec![&self.name, &self.parameters];
+        if let Some(return_type) = &self.return_type {
+            children.push(return_type);
+        }
+        children
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -363,6 +497,15 @@ impl Node for ConcreteMethod {
         children.push(&mut self.body);
         children
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        let mut children: Vec<&dyn Node> = vec![&self.name, &self.parameters];
+        if let Some(return_type) = &self.return_type {
+            children.push(return_type);
+        }
+        children.push(&self.body);
+        children
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -381,4 +524,8 @@ impl Node for MethodBody {
             .map(|s| s as &mut dyn Node)
             .collect()
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        self.statements.iter().map(|s| s as &dyn Node).collect()
+    }
 }
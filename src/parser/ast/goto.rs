@@ -6,6 +6,7 @@ use crate::lexer::token::Span;
 use crate::node::Node;
 use crate::parser::ast::comments::CommentGroup;
 use crate::parser::ast::identifiers::SimpleIdentifier;
+use crate::parser::ast::Ending;
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
 
@@ -19,6 +20,10 @@ impl Node for LabelStatement {
     fn children(&mut self) -> Vec<&mut dyn Node> {
         vec![&mut self.label]
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        vec![&self.label]
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -27,11 +32,15 @@ pub struct GotoStatement {
     pub comments: CommentGroup,
     pub keyword: Span,           // `goto`
     pub label: SimpleIdentifier, // `foo`
-    pub semicolon: Span,         // `;`
+    pub ending: Ending,          // `;` or `?>`
 }
 
 impl Node for GotoStatement {
     fn children(&mut self) -> Vec<&mut dyn Node> {
         vec![&mut self.label]
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        vec![&self.label]
+    }
 }
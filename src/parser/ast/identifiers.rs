@@ -23,6 +23,13 @@ impl Node for Identifier {
             Identifier::DynamicIdentifier(identifier) => identifier.children(),
         }
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        match self {
+            Identifier::SimpleIdentifier(identifier) => identifier.children_ref(),
+            Identifier::DynamicIdentifier(identifier) => identifier.children_ref(),
+        }
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -36,12 +43,93 @@ impl Node for SimpleIdentifier {
     //
 }
 
+impl SimpleIdentifier {
+    /// Split this identifier into a structured [`Name`].
+    pub fn to_name(&self) -> Name {
+        Name::new(self.span, &self.value)
+    }
+}
+
 impl Display for SimpleIdentifier {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.value)
     }
 }
 
+/// A class, function, or constant name, split into its namespace segments.
+///
+/// `Foo` and `Foo\Bar` are relative to the current namespace (and imports),
+/// while `\Foo\Bar` is fully qualified.
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+
+pub struct Name {
+    pub span: Span,
+    pub segments: Vec<ByteString>,
+    pub fully_qualified: bool,
+}
+
+impl Name {
+    pub fn new(span: Span, value: &[u8]) -> Self {
+        let fully_qualified = value.starts_with(b"\\");
+        let value = if fully_qualified { &value[1..] } else { value };
+        // the prefix of a group use statement includes the trailing separator, e.g. `Foo\`.
+        let value = value.strip_suffix(b"\\").unwrap_or(value);
+
+        Self {
+            span,
+            segments: value
+                .split(|byte| *byte == b'\\')
+                .map(|segment| ByteString::new(segment.to_vec()))
+                .collect(),
+            fully_qualified,
+        }
+    }
+
+    /// Determine whether this name is a single unqualified segment, e.g. `Foo`.
+    pub fn is_unqualified(&self) -> bool {
+        !self.fully_qualified && self.segments.len() == 1
+    }
+
+    /// Determine whether this name is relative to the current namespace, e.g. `namespace\Foo`.
+    pub fn is_namespace_relative(&self) -> bool {
+        !self.fully_qualified
+            && self.segments.len() > 1
+            && self.first().eq_ignore_ascii_case(b"namespace")
+    }
+
+    /// The first segment of the name, e.g. `Foo` in `Foo\Bar`.
+    pub fn first(&self) -> &ByteString {
+        &self.segments[0]
+    }
+
+    /// The last segment of the name, e.g. `Bar` in `Foo\Bar`.
+    pub fn last(&self) -> &ByteString {
+        &self.segments[self.segments.len() - 1]
+    }
+}
+
+impl Node for Name {
+    //
+}
+
+impl Display for Name {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.fully_qualified {
+            write!(f, "\\")?;
+        }
+
+        for (index, segment) in self.segments.iter().enumerate() {
+            if index > 0 {
+                write!(f, "\\")?;
+            }
+
+            write!(f, "{}", segment)?;
+        }
+
+        Ok(())
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
 
 pub struct DynamicIdentifier {
@@ -54,4 +142,36 @@ impl Node for DynamicIdentifier {
     fn children(&mut self) -> Vec<&mut dyn Node> {
         vec![self.expr.as_mut()]
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        vec![self.expr.as_ref()]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_name_segments() {
+        let span = Span::new(1, 1, 0);
+
+        let name = Name::new(span, b"Foo");
+        assert!(name.is_unqualified());
+        assert_eq!(name.segments, vec![ByteString::from("Foo")]);
+
+        let name = Name::new(span, b"Foo\\Bar");
+        assert!(!name.is_unqualified());
+        assert!(!name.fully_qualified);
+        assert_eq!(name.first(), &ByteString::from("Foo"));
+        assert_eq!(name.last(), &ByteString::from("Bar"));
+
+        let name = Name::new(span, b"\\Foo\\Bar");
+        assert!(name.fully_qualified);
+        assert_eq!(name.segments.len(), 2);
+        assert_eq!(name.to_string(), "\\Foo\\Bar");
+
+        let name = Name::new(span, b"Foo\\Bar\\");
+        assert_eq!(name.to_string(), "Foo\\Bar");
+    }
 }
@@ -5,9 +5,11 @@ use serde::Serialize;
 use crate::lexer::token::Span;
 use crate::node::Node;
 use crate::parser::ast::attributes::AttributeGroup;
+use crate::parser::ast::comments::CommentGroup;
 use crate::parser::ast::constant::ClassishConstant;
 use crate::parser::ast::functions::AbstractConstructor;
 use crate::parser::ast::functions::AbstractMethod;
+use crate::parser::ast::identifiers::Name;
 use crate::parser::ast::identifiers::SimpleIdentifier;
 use crate::parser::ast::utils::CommaSeparated;
 
@@ -27,19 +29,31 @@ impl Node for InterfaceMember {
             InterfaceMember::Method(method) => vec![method],
         }
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        match self {
+            InterfaceMember::Constant(constant) => vec![constant],
+            InterfaceMember::Constructor(constructor) => vec![constructor],
+            InterfaceMember::Method(method) => vec![method],
+        }
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
 
 pub struct InterfaceExtends {
-    pub extends: Span,                             // `extends`
-    pub parents: CommaSeparated<SimpleIdentifier>, // `Foo`, `Bar`
+    pub extends: Span,                 // `extends`
+    pub parents: CommaSeparated<Name>, // `Foo`, `Bar`
 }
 
 impl Node for InterfaceExtends {
     fn children(&mut self) -> Vec<&mut dyn Node> {
         self.parents.children()
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        self.parents.children_ref()
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -57,11 +71,19 @@ impl Node for InterfaceBody {
             .map(|member| member as &mut dyn Node)
             .collect()
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        self.members
+            .iter()
+            .map(|member| member as &dyn Node)
+            .collect()
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
 
 pub struct InterfaceStatement {
+    pub comments: CommentGroup,
     pub attributes: Vec<AttributeGroup>,   // `#[Foo]`
     pub interface: Span,                   // `interface`
     pub name: SimpleIdentifier,            // `Foo`
@@ -77,4 +99,12 @@ impl Node for InterfaceStatement {
         }
         children
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        let mut children: Vec<&dyn Node> = vec![&self.name];
+        if let Some(extends) = &self.extends {
+            children.push(extends);
+        }
+        children
+    }
 }
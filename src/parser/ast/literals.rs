@@ -3,6 +3,8 @@ use serde::Deserialize;
 use serde::Serialize;
 
 use crate::lexer::byte_string::ByteString;
+use crate::lexer::decode_integer;
+use crate::lexer::integer_digits;
 use crate::lexer::token::Span;
 use crate::node::Node;
 
@@ -22,6 +24,14 @@ impl Node for Literal {
             Literal::Float(literal) => vec![literal],
         }
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        match self {
+            Literal::String(literal) => vec![literal],
+            Literal::Integer(literal) => vec![literal],
+            Literal::Float(literal) => vec![literal],
+        }
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -49,6 +59,19 @@ pub struct LiteralInteger {
     pub span: Span,
 }
 
+impl LiteralInteger {
+    /// Decode the value of this integer literal.
+    ///
+    /// Binary (`0b`), octal (`0o`, or a leading `0`), hexadecimal (`0x`) and
+    /// decimal literals are supported, with or without numeric separators
+    /// (`1_000`). Integers that do not fit in an `i64` are lexed as floats,
+    /// as they are in PHP, so `None` is only returned for literals that were
+    /// not produced by the lexer.
+    pub fn to_i64(&self) -> Option<i64> {
+        decode_integer(&self.value).ok()
+    }
+}
+
 impl Node for LiteralInteger {
     //
 }
@@ -60,6 +83,86 @@ pub struct LiteralFloat {
     pub span: Span,
 }
 
+impl LiteralFloat {
+    /// Decode the value of this float literal.
+    ///
+    /// Besides the usual forms (`1.5`, `.5`, `1.`, `1.5e10`), this includes
+    /// integer literals too large to fit in an `i64`, which are lexed as
+    /// floats and may use any of the integer bases.
+    pub fn to_f64(&self) -> Option<f64> {
+        let value: Vec<u8> = self.value.iter().copied().filter(|b| *b != b'_').collect();
+
+        let (digits, radix) = match &value[..] {
+            [b'0', b'x' | b'X' | b'o' | b'O' | b'b' | b'B', ..] => integer_digits(&value),
+            [b'0', digits @ ..] if digits.iter().all(u8::is_ascii_digit) => (digits, 8),
+            _ => return String::from_utf8_lossy(&value).parse().ok(),
+        };
+
+        // same as PHP, accumulate the digits of integers in other bases.
+        digits.iter().try_fold(0.0, |value, digit| {
+            let digit = (*digit as char).to_digit(radix)?;
+
+            Some(value * radix as f64 + digit as f64)
+        })
+    }
+}
+
 impl Node for LiteralFloat {
     //
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn integer(value: &str) -> Option<i64> {
+        LiteralInteger {
+            value: value.into(),
+            span: Span::new(1, 1, 0),
+        }
+        .to_i64()
+    }
+
+    #[test]
+    fn test_literal_integer_to_i64() {
+        assert_eq!(integer("0"), Some(0));
+        assert_eq!(integer("42"), Some(42));
+        assert_eq!(integer("0x1A"), Some(26));
+        assert_eq!(integer("0XFF"), Some(255));
+        assert_eq!(integer("0o17"), Some(15));
+        assert_eq!(integer("017"), Some(15));
+        assert_eq!(integer("0b101"), Some(5));
+        assert_eq!(integer("9223372036854775807"), Some(i64::MAX));
+        assert_eq!(integer("9223372036854775808"), None);
+        assert_eq!(integer("09"), None);
+        assert_eq!(integer("1_000_000"), Some(1_000_000));
+        assert_eq!(integer("0xFF_FF"), Some(0xFFFF));
+        assert_eq!(integer("0b1_0"), Some(2));
+    }
+
+    fn float(value: &str) -> Option<f64> {
+        LiteralFloat {
+            value: value.into(),
+            span: Span::new(1, 1, 0),
+        }
+        .to_f64()
+    }
+
+    #[test]
+    fn test_literal_float_to_f64() {
+        assert_eq!(float("1.5"), Some(1.5));
+        assert_eq!(float(".5"), Some(0.5));
+        assert_eq!(float("1."), Some(1.0));
+        assert_eq!(float("0.25"), Some(0.25));
+        assert_eq!(float("1.5e10"), Some(1.5e10));
+        assert_eq!(float("1e-3"), Some(1e-3));
+        assert_eq!(float("2E+2"), Some(200.0));
+        assert_eq!(float("1_000.25_5"), Some(1000.255));
+        assert_eq!(float("9223372036854775808"), Some(9223372036854775808.0));
+        assert_eq!(float("0xFFFFFFFFFFFFFFFF"), Some(18446744073709551615.0));
+        assert_eq!(
+            float("0b1_0000000000000000000000000000000000000000000000000000000000000000"),
+            Some(18446744073709551616.0)
+        );
+    }
+}
@@ -24,6 +24,10 @@ impl Node for ForeachStatement {
     fn children(&mut self) -> Vec<&mut dyn Node> {
         vec![&mut self.iterator, &mut self.body]
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        vec![&self.iterator, &self.body]
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -63,6 +67,22 @@ impl Node for ForeachStatementIterator {
             } => vec![expression, key, value],
         }
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        match self {
+            ForeachStatementIterator::Value {
+                expression, value, ..
+            } => {
+                vec![expression, value]
+            }
+            ForeachStatementIterator::KeyAndValue {
+                expression,
+                key,
+                value,
+                ..
+            } => vec![expression, key, value],
+        }
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -88,6 +108,15 @@ impl Node for ForeachStatementBody {
             }
         }
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        match self {
+            ForeachStatementBody::Statement { statement } => vec![statement.as_ref()],
+            ForeachStatementBody::Block { statements, .. } => {
+                statements.iter().map(|s| s as &dyn Node).collect()
+            }
+        }
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -104,6 +133,10 @@ impl Node for ForStatement {
     fn children(&mut self) -> Vec<&mut dyn Node> {
         vec![&mut self.iterator, &mut self.body]
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        vec![&self.iterator, &self.body]
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -129,6 +162,14 @@ impl Node for ForStatementIterator {
         children.extend(self.r#loop.inner.iter_mut().map(|x| x as &mut dyn Node));
         children
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        let mut children = vec![];
+        children.extend(self.initializations.inner.iter().map(|x| x as &dyn Node));
+        children.extend(self.conditions.inner.iter().map(|x| x as &dyn Node));
+        children.extend(self.r#loop.inner.iter().map(|x| x as &dyn Node));
+        children
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -154,6 +195,15 @@ impl Node for ForStatementBody {
             }
         }
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        match self {
+            ForStatementBody::Statement { statement } => vec![statement.as_ref()],
+            ForStatementBody::Block { statements, .. } => {
+                statements.iter().map(|x| x as &dyn Node).collect()
+            }
+        }
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -165,13 +215,17 @@ pub struct DoWhileStatement {
     pub left_parenthesis: Span,  // `(`
     pub condition: Expression,   // `( *expression* )`
     pub right_parenthesis: Span, // `)`
-    pub semicolon: Span,         // `;`
+    pub ending: Ending,          // `;` or `?>`
 }
 
 impl Node for DoWhileStatement {
     fn children(&mut self) -> Vec<&mut dyn Node> {
         vec![self.body.as_mut(), &mut self.condition]
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        vec![self.body.as_ref(), &self.condition]
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -188,6 +242,10 @@ impl Node for WhileStatement {
     fn children(&mut self) -> Vec<&mut dyn Node> {
         vec![&mut self.condition, &mut self.body]
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        vec![&self.condition, &self.body]
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -213,6 +271,15 @@ impl Node for WhileStatementBody {
             }
         }
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        match self {
+            WhileStatementBody::Statement { statement } => vec![statement.as_ref()],
+            WhileStatementBody::Block { statements, .. } => {
+                statements.iter().map(|s| s as &dyn Node).collect()
+            }
+        }
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -233,6 +300,13 @@ impl Node for Level {
             Level::Parenthesized { level, .. } => level.children(),
         }
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        match self {
+            Level::Literal(literal) => vec![literal],
+            Level::Parenthesized { level, .. } => level.children_ref(),
+        }
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -250,6 +324,13 @@ impl Node for BreakStatement {
             None => vec![],
         }
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        match &self.level {
+            Some(level) => vec![level],
+            None => vec![],
+        }
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -267,4 +348,11 @@ impl Node for ContinueStatement {
             None => vec![],
         }
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        match &self.level {
+            Some(level) => vec![level],
+            None => vec![],
+        }
+    }
 }
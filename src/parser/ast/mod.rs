@@ -22,6 +22,7 @@ use crate::parser::ast::functions::FunctionStatement;
 use crate::parser::ast::goto::GotoStatement;
 use crate::parser::ast::goto::LabelStatement;
 use crate::parser::ast::identifiers::Identifier;
+use crate::parser::ast::identifiers::Name;
 use crate::parser::ast::identifiers::SimpleIdentifier;
 use crate::parser::ast::interfaces::InterfaceStatement;
 use crate::parser::ast::literals::Literal;
@@ -72,6 +73,10 @@ impl Node for Block {
     fn children(&mut self) -> Vec<&mut dyn Node> {
         self.iter_mut().map(|s| s as &mut dyn Node).collect()
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        self.iter().map(|s| s as &dyn Node).collect()
+    }
 }
 
 pub type Program = Block;
@@ -99,6 +104,14 @@ impl Node for StaticVar {
         }
         children
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        let mut children: Vec<&dyn Node> = vec![&self.var];
+        if let Some(default) = &self.default {
+            children.push(default);
+        }
+        children
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -111,6 +124,13 @@ pub enum Ending {
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(tag = "type")]
 pub struct HaltCompilerStatement {
+    pub halt_compiler: Span,
+    pub left_parenthesis: Span,
+    pub right_parenthesis: Span,
+    pub ending: Ending,
+    // the byte offset at which the data following the statement begins,
+    // i.e. the value of `__COMPILER_HALT_OFFSET__`.
+    pub offset: usize,
     pub content: Option<ByteString>,
 }
 
@@ -126,6 +146,10 @@ impl Node for StaticStatement {
     fn children(&mut self) -> Vec<&mut dyn Node> {
         self.vars.iter_mut().map(|v| v as &mut dyn Node).collect()
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        self.vars.iter().map(|v| v as &dyn Node).collect()
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -144,6 +168,12 @@ impl Node for SwitchStatement {
         children.extend(self.cases.iter_mut().map(|c| c as &mut dyn Node));
         children
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        let mut children: Vec<&dyn Node> = vec![&self.condition];
+        children.extend(self.cases.iter().map(|c| c as &dyn Node));
+        children
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -158,6 +188,10 @@ impl Node for EchoStatement {
     fn children(&mut self) -> Vec<&mut dyn Node> {
         self.values.iter_mut().map(|v| v as &mut dyn Node).collect()
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        self.values.iter().map(|v| v as &dyn Node).collect()
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -176,6 +210,14 @@ impl Node for ReturnStatement {
             vec![]
         }
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        if let Some(value) = &self.value {
+            vec![value]
+        } else {
+            vec![]
+        }
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -189,12 +231,16 @@ impl Node for UseStatement {
     fn children(&mut self) -> Vec<&mut dyn Node> {
         self.uses.iter_mut().map(|u| u as &mut dyn Node).collect()
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        self.uses.iter().map(|u| u as &dyn Node).collect()
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(tag = "type")]
 pub struct GroupUseStatement {
-    pub prefix: SimpleIdentifier,
+    pub prefix: Name,
     pub kind: UseKind,
     pub uses: Vec<Use>,
 }
@@ -205,6 +251,12 @@ impl Node for GroupUseStatement {
         children.extend(self.uses.iter_mut().map(|u| u as &mut dyn Node));
         children
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        let mut children: Vec<&dyn Node> = vec![&self.prefix];
+        children.extend(self.uses.iter().map(|u| u as &dyn Node));
+        children
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -246,6 +298,8 @@ pub enum Statement {
     Global(GlobalStatement),
     Declare(DeclareStatement),
     Noop(Span),
+    // a malformed statement, starting at the given span.
+    Error(Span),
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -314,6 +368,42 @@ impl Node for Statement {
             _ => vec![],
         }
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        match self {
+            Statement::Label(statement) => vec![statement],
+            Statement::Goto(statement) => vec![statement],
+            Statement::HaltCompiler(statement) => vec![statement],
+            Statement::Static(statement) => vec![statement],
+            Statement::DoWhile(statement) => vec![statement],
+            Statement::While(statement) => vec![statement],
+            Statement::For(statement) => vec![statement],
+            Statement::Foreach(statement) => vec![statement],
+            Statement::Break(statement) => vec![statement],
+            Statement::Continue(statement) => vec![statement],
+            Statement::Constant(statement) => vec![statement],
+            Statement::Function(statement) => vec![statement],
+            Statement::Class(statement) => vec![statement],
+            Statement::Trait(statement) => vec![statement],
+            Statement::Interface(statement) => vec![statement],
+            Statement::If(statement) => vec![statement],
+            Statement::Switch(statement) => vec![statement],
+            Statement::Echo(statement) => vec![statement],
+            Statement::Expression(statement) => vec![statement],
+            Statement::Return(statement) => vec![statement],
+            Statement::Namespace(statement) => vec![statement],
+            Statement::Use(statement) => vec![statement],
+            Statement::GroupUse(statement) => vec![statement],
+            Statement::Comment(statement) => vec![statement],
+            Statement::Try(statement) => vec![statement],
+            Statement::UnitEnum(statement) => vec![statement],
+            Statement::BackedEnum(statement) => vec![statement],
+            Statement::Block(statement) => vec![statement],
+            Statement::Global(statement) => vec![statement],
+            Statement::Declare(statement) => vec![statement],
+            _ => vec![],
+        }
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -327,6 +417,10 @@ impl Node for ExpressionStatement {
     fn children(&mut self) -> Vec<&mut dyn Node> {
         vec![&mut self.expression]
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        vec![&self.expression]
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -343,6 +437,10 @@ impl Node for GlobalStatement {
             .map(|v| v as &mut dyn Node)
             .collect()
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        self.variables.iter().map(|v| v as &dyn Node).collect()
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -360,6 +458,10 @@ impl Node for BlockStatement {
             .map(|s| s as &mut dyn Node)
             .collect()
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        self.statements.iter().map(|s| s as &dyn Node).collect()
+    }
 }
 
 // See https://www.php.net/manual/en/language.types.type-juggling.php#language.types.typecasting for more info.
@@ -417,12 +519,26 @@ impl Node for Case {
         );
         children
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        let mut children: Vec<&dyn Node> = vec![];
+        if let Some(condition) = &self.condition {
+            children.push(condition);
+        }
+        children.extend(
+            self.body
+                .iter()
+                .map(|statement| statement as &dyn Node)
+                .collect::<Vec<&dyn Node>>(),
+        );
+        children
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
 
 pub struct Use {
-    pub name: SimpleIdentifier,
+    pub name: Name,
     pub alias: Option<SimpleIdentifier>,
     pub kind: Option<UseKind>,
 }
@@ -435,6 +551,14 @@ impl Node for Use {
         }
         children
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        let mut children: Vec<&dyn Node> = vec![&self.name];
+        if let Some(alias) = &self.alias {
+            children.push(alias);
+        }
+        children
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -556,6 +680,10 @@ impl Node for FunctionCallExpression {
     fn children(&mut self) -> Vec<&mut dyn Node> {
         vec![self.target.as_mut(), &mut self.arguments]
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        vec![self.target.as_ref(), &self.arguments]
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -569,6 +697,10 @@ impl Node for FunctionClosureCreationExpression {
     fn children(&mut self) -> Vec<&mut dyn Node> {
         vec![self.target.as_mut()]
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        vec![self.target.as_ref()]
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -590,6 +722,10 @@ impl Node for MethodCallExpression {
             &mut self.arguments,
         ]
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        vec![self.target.as_ref(), self.method.as_ref(), &self.arguments]
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -607,6 +743,10 @@ impl Node for MethodClosureCreationExpression {
     fn children(&mut self) -> Vec<&mut dyn Node> {
         vec![self.target.as_mut(), self.method.as_mut()]
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        vec![self.target.as_ref(), self.method.as_ref()]
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -628,6 +768,10 @@ impl Node for NullsafeMethodCallExpression {
             &mut self.arguments,
         ]
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        vec![self.target.as_ref(), self.method.as_ref(), &self.arguments]
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -645,6 +789,10 @@ impl Node for StaticMethodCallExpression {
     fn children(&mut self) -> Vec<&mut dyn Node> {
         vec![self.target.as_mut(), &mut self.method, &mut self.arguments]
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        vec![self.target.as_ref(), &self.method, &self.arguments]
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -662,6 +810,10 @@ impl Node for StaticVariableMethodCallExpression {
     fn children(&mut self) -> Vec<&mut dyn Node> {
         vec![self.target.as_mut(), &mut self.method, &mut self.arguments]
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        vec![self.target.as_ref(), &self.method, &self.arguments]
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -679,6 +831,10 @@ impl Node for StaticMethodClosureCreationExpression {
     fn children(&mut self) -> Vec<&mut dyn Node> {
         vec![self.target.as_mut(), &mut self.method]
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        vec![self.target.as_ref(), &self.method]
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -696,6 +852,10 @@ impl Node for StaticVariableMethodClosureCreationExpression {
     fn children(&mut self) -> Vec<&mut dyn Node> {
         vec![self.target.as_mut(), &mut self.method]
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        vec![self.target.as_ref(), &self.method]
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -711,6 +871,10 @@ impl Node for PropertyFetchExpression {
     fn children(&mut self) -> Vec<&mut dyn Node> {
         vec![self.target.as_mut(), self.property.as_mut()]
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        vec![self.target.as_ref(), self.property.as_ref()]
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -726,6 +890,10 @@ impl Node for NullsafePropertyFetchExpression {
     fn children(&mut self) -> Vec<&mut dyn Node> {
         vec![self.target.as_mut(), self.property.as_mut()]
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        vec![self.target.as_ref(), self.property.as_ref()]
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -741,6 +909,10 @@ impl Node for StaticPropertyFetchExpression {
     fn children(&mut self) -> Vec<&mut dyn Node> {
         vec![self.target.as_mut(), &mut self.property]
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        vec![self.target.as_ref(), &self.property]
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -756,6 +928,29 @@ impl Node for ConstantFetchExpression {
     fn children(&mut self) -> Vec<&mut dyn Node> {
         vec![self.target.as_mut(), &mut self.constant]
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        vec![self.target.as_ref(), &self.constant]
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
+pub struct ClassNameExpression {
+    pub target: Box<Expression>,
+    // `foo()`
+    pub double_colon: Span,
+    // `::`
+    pub class: Span, // `class`
+}
+
+impl Node for ClassNameExpression {
+    fn children(&mut self) -> Vec<&mut dyn Node> {
+        vec![self.target.as_mut()]
+    }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        vec![self.target.as_ref()]
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -771,6 +966,10 @@ impl Node for ShortArrayExpression {
     fn children(&mut self) -> Vec<&mut dyn Node> {
         vec![&mut self.items]
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        vec![&self.items]
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -788,6 +987,10 @@ impl Node for ArrayExpression {
     fn children(&mut self) -> Vec<&mut dyn Node> {
         vec![&mut self.items]
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        vec![&self.items]
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -805,6 +1008,10 @@ impl Node for ListExpression {
     fn children(&mut self) -> Vec<&mut dyn Node> {
         self.items.iter_mut().map(|i| i as &mut dyn Node).collect()
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        self.items.iter().map(|i| i as &dyn Node).collect()
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -824,6 +1031,14 @@ impl Node for NewExpression {
         }
         children
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        let mut children: Vec<&dyn Node> = vec![self.target.as_ref()];
+        if let Some(arguments) = &self.arguments {
+            children.push(arguments);
+        }
+        children
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -838,6 +1053,10 @@ impl Node for InterpolatedStringExpression {
             .map(|part| part as &mut dyn Node)
             .collect()
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        self.parts.iter().map(|part| part as &dyn Node).collect()
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -853,6 +1072,10 @@ impl Node for HeredocExpression {
             .map(|part| part as &mut dyn Node)
             .collect()
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        self.parts.iter().map(|part| part as &dyn Node).collect()
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -875,6 +1098,10 @@ impl Node for ShellExecExpression {
             .map(|part| part as &mut dyn Node)
             .collect()
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        self.parts.iter().map(|part| part as &dyn Node).collect()
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -900,6 +1127,14 @@ impl Node for ArrayIndexExpression {
         }
         children
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        let mut children: Vec<&dyn Node> = vec![];
+        if let Some(index) = &self.index {
+            children.push(index.as_ref());
+        }
+        children
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -915,6 +1150,10 @@ impl Node for ShortTernaryExpression {
     fn children(&mut self) -> Vec<&mut dyn Node> {
         vec![self.condition.as_mut(), self.r#else.as_mut()]
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        vec![self.condition.as_ref(), self.r#else.as_ref()]
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -938,6 +1177,14 @@ impl Node for TernaryExpression {
             self.r#else.as_mut(),
         ]
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        vec![
+            self.condition.as_ref(),
+            self.then.as_ref(),
+            self.r#else.as_ref(),
+        ]
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -951,6 +1198,10 @@ impl Node for CoalesceExpression {
     fn children(&mut self) -> Vec<&mut dyn Node> {
         vec![self.lhs.as_mut(), self.rhs.as_mut()]
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        vec![self.lhs.as_ref(), self.rhs.as_ref()]
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -962,6 +1213,10 @@ impl Node for CloneExpression {
     fn children(&mut self) -> Vec<&mut dyn Node> {
         vec![self.target.as_mut()]
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        vec![self.target.as_ref()]
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -990,6 +1245,20 @@ impl Node for MatchExpression {
         );
         children
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        let mut children: Vec<&dyn Node> = vec![self.condition.as_ref()];
+        if let Some(default) = &self.default {
+            children.push(default.as_ref());
+        }
+        children.extend(
+            self.arms
+                .iter()
+                .map(|arm| arm as &dyn Node)
+                .collect::<Vec<&dyn Node>>(),
+        );
+        children
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -1001,6 +1270,10 @@ impl Node for ThrowExpression {
     fn children(&mut self) -> Vec<&mut dyn Node> {
         vec![self.value.as_mut()]
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        vec![self.value.as_ref()]
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -1020,6 +1293,17 @@ impl Node for YieldExpression {
         }
         children
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        let mut children: Vec<&dyn Node> = vec![];
+        if let Some(key) = &self.key {
+            children.push(key.as_ref());
+        }
+        if let Some(value) = &self.value {
+            children.push(value.as_ref());
+        }
+        children
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -1031,6 +1315,10 @@ impl Node for YieldFromExpression {
     fn children(&mut self) -> Vec<&mut dyn Node> {
         vec![self.value.as_mut()]
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        vec![self.value.as_ref()]
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -1044,6 +1332,10 @@ impl Node for CastExpression {
     fn children(&mut self) -> Vec<&mut dyn Node> {
         vec![self.value.as_mut()]
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        vec![self.value.as_ref()]
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -1117,6 +1409,8 @@ pub enum Expression {
     StaticPropertyFetch(StaticPropertyFetchExpression),
     // `foo()::bar` or `foo()::{$name}`
     ConstantFetch(ConstantFetchExpression),
+    // `Foo::class` or `$foo::class`
+    ClassName(ClassNameExpression),
     // `static`
     Static,
     // `self`
@@ -1173,18 +1467,28 @@ pub enum Expression {
     Cast(CastExpression),
     // ;
     Noop,
+    // a malformed expression, starting at the given span.
+    Error(Span),
 }
 
 impl Node for EvalExpression {
     fn children(&mut self) -> Vec<&mut dyn Node> {
         vec![self.argument.as_mut()]
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        vec![self.argument.as_ref()]
+    }
 }
 
 impl Node for EmptyExpression {
     fn children(&mut self) -> Vec<&mut dyn Node> {
         vec![self.argument.as_mut()]
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        vec![self.argument.as_ref()]
+    }
 }
 
 impl Node for DieExpression {
@@ -1195,6 +1499,14 @@ impl Node for DieExpression {
             vec![]
         }
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        if let Some(argument) = &self.argument {
+            vec![argument.as_ref()]
+        } else {
+            vec![]
+        }
+    }
 }
 
 impl Node for ExitExpression {
@@ -1205,18 +1517,34 @@ impl Node for ExitExpression {
             vec![]
         }
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        if let Some(argument) = &self.argument {
+            vec![argument.as_ref()]
+        } else {
+            vec![]
+        }
+    }
 }
 
 impl Node for IssetExpression {
     fn children(&mut self) -> Vec<&mut dyn Node> {
         vec![&mut self.arguments]
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        vec![&self.arguments]
+    }
 }
 
 impl Node for UnsetExpression {
     fn children(&mut self) -> Vec<&mut dyn Node> {
         vec![&mut self.arguments]
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        vec![&self.arguments]
+    }
 }
 
 impl Node for PrintExpression {
@@ -1229,60 +1557,131 @@ impl Node for PrintExpression {
             vec![]
         }
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        if let Some(argument) = &self.argument {
+            vec![argument.as_ref()]
+        } else if let Some(value) = &self.value {
+            vec![value.as_ref()]
+        } else {
+            vec![]
+        }
+    }
 }
 
 impl Node for ConcatExpression {
     fn children(&mut self) -> Vec<&mut dyn Node> {
         vec![self.left.as_mut(), self.right.as_mut()]
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        vec![self.left.as_ref(), self.right.as_ref()]
+    }
 }
 
 impl Node for InstanceofExpression {
     fn children(&mut self) -> Vec<&mut dyn Node> {
         vec![self.left.as_mut(), self.right.as_mut()]
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        vec![self.left.as_ref(), self.right.as_ref()]
+    }
 }
 
 impl Node for ReferenceExpression {
     fn children(&mut self) -> Vec<&mut dyn Node> {
         vec![self.right.as_mut()]
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        vec![self.right.as_ref()]
+    }
 }
 
 impl Node for ParenthesizedExpression {
     fn children(&mut self) -> Vec<&mut dyn Node> {
         vec![self.expr.as_mut()]
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        vec![self.expr.as_ref()]
+    }
 }
 
 impl Node for ErrorSuppressExpression {
     fn children(&mut self) -> Vec<&mut dyn Node> {
         vec![self.expr.as_mut()]
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        vec![self.expr.as_ref()]
+    }
 }
 
 impl Node for IncludeExpression {
     fn children(&mut self) -> Vec<&mut dyn Node> {
         vec![self.path.as_mut()]
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        vec![self.path.as_ref()]
+    }
 }
 
 impl Node for IncludeOnceExpression {
     fn children(&mut self) -> Vec<&mut dyn Node> {
         vec![self.path.as_mut()]
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        vec![self.path.as_ref()]
+    }
 }
 
 impl Node for RequireExpression {
     fn children(&mut self) -> Vec<&mut dyn Node> {
         vec![self.path.as_mut()]
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        vec![self.path.as_ref()]
+    }
 }
 
 impl Node for RequireOnceExpression {
     fn children(&mut self) -> Vec<&mut dyn Node> {
         vec![self.path.as_mut()]
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        vec![self.path.as_ref()]
+    }
+}
+
+impl Expression {
+    /// Whether this expression can be written to, e.g. incremented or decremented.
+    pub fn is_writable(&self) -> bool {
+        matches!(
+            self,
+            Expression::Variable(_)
+                | Expression::PropertyFetch(_)
+                | Expression::StaticPropertyFetch(_)
+                | Expression::ArrayIndex(_)
+        )
+    }
+
+    /// Whether a reference can be taken to this expression, e.g. `$a = &$b`.
+    pub fn is_referencable(&self) -> bool {
+        self.is_writable()
+            || matches!(
+                self,
+                Expression::FunctionCall(_)
+                    | Expression::MethodCall(_)
+                    | Expression::StaticMethodCall(_)
+                    | Expression::StaticVariableMethodCall(_)
+            )
+    }
 }
 
 impl Node for Expression {
@@ -1325,6 +1724,7 @@ impl Node for Expression {
             Expression::NullsafePropertyFetch(expression) => vec![expression],
             Expression::StaticPropertyFetch(expression) => vec![expression],
             Expression::ConstantFetch(expression) => vec![expression],
+            Expression::ClassName(expression) => vec![expression],
             Expression::Static => vec![],
             Expression::Self_ => vec![],
             Expression::Parent => vec![],
@@ -1353,6 +1753,79 @@ impl Node for Expression {
             Expression::YieldFrom(expression) => vec![expression],
             Expression::Cast(expression) => vec![expression],
             Expression::Noop => vec![],
+            Expression::Error(_) => vec![],
+        }
+    }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        match self {
+            Expression::Eval(expression) => vec![expression],
+            Expression::Empty(expression) => vec![expression],
+            Expression::Die(expression) => vec![expression],
+            Expression::Exit(expression) => vec![expression],
+            Expression::Isset(expression) => vec![expression],
+            Expression::Unset(expression) => vec![expression],
+            Expression::Print(expression) => vec![expression],
+            Expression::Literal(literal) => vec![literal],
+            Expression::ArithmeticOperation(operation) => vec![operation],
+            Expression::AssignmentOperation(operation) => vec![operation],
+            Expression::BitwiseOperation(operation) => vec![operation],
+            Expression::ComparisonOperation(operation) => vec![operation],
+            Expression::LogicalOperation(operation) => vec![operation],
+            Expression::Concat(expression) => vec![expression],
+            Expression::Instanceof(expression) => vec![expression],
+            Expression::Reference(expression) => vec![expression],
+            Expression::Parenthesized(expression) => vec![expression],
+            Expression::ErrorSuppress(expression) => vec![expression],
+            Expression::Identifier(identifier) => vec![identifier],
+            Expression::Variable(variable) => vec![variable],
+            Expression::Include(expression) => vec![expression],
+            Expression::IncludeOnce(expression) => vec![expression],
+            Expression::Require(expression) => vec![expression],
+            Expression::RequireOnce(expression) => vec![expression],
+            Expression::FunctionCall(expression) => vec![expression],
+            Expression::FunctionClosureCreation(expression) => vec![expression],
+            Expression::MethodCall(expression) => vec![expression],
+            Expression::MethodClosureCreation(expression) => vec![expression],
+            Expression::NullsafeMethodCall(expression) => vec![expression],
+            Expression::StaticMethodCall(expression) => vec![expression],
+            Expression::StaticVariableMethodCall(expression) => vec![expression],
+            Expression::StaticMethodClosureCreation(expression) => vec![expression],
+            Expression::StaticVariableMethodClosureCreation(expression) => vec![expression],
+            Expression::PropertyFetch(expression) => vec![expression],
+            Expression::NullsafePropertyFetch(expression) => vec![expression],
+            Expression::StaticPropertyFetch(expression) => vec![expression],
+            Expression::ConstantFetch(expression) => vec![expression],
+            Expression::ClassName(expression) => vec![expression],
+            Expression::Static => vec![],
+            Expression::Self_ => vec![],
+            Expression::Parent => vec![],
+            Expression::ShortArray(expression) => vec![expression],
+            Expression::Array(expression) => vec![expression],
+            Expression::List(expression) => vec![expression],
+            Expression::Closure(expression) => vec![expression],
+            Expression::ArrowFunction(expression) => vec![expression],
+            Expression::New(expression) => vec![expression],
+            Expression::InterpolatedString(expression) => vec![expression],
+            Expression::Heredoc(expression) => vec![expression],
+            Expression::Nowdoc(expression) => vec![expression],
+            Expression::ShellExec(expression) => vec![expression],
+            Expression::AnonymousClass(expression) => vec![expression],
+            Expression::Bool(_) => vec![],
+            Expression::ArrayIndex(expression) => vec![expression],
+            Expression::Null => vec![],
+            Expression::MagicConstant(constant) => vec![constant],
+            Expression::ShortTernary(expression) => vec![expression],
+            Expression::Ternary(expression) => vec![expression],
+            Expression::Coalesce(expression) => vec![expression],
+            Expression::Clone(expression) => vec![expression],
+            Expression::Match(expression) => vec![expression],
+            Expression::Throw(expression) => vec![expression],
+            Expression::Yield(expression) => vec![expression],
+            Expression::YieldFrom(expression) => vec![expression],
+            Expression::Cast(expression) => vec![expression],
+            Expression::Noop => vec![],
+            Expression::Error(_) => vec![],
         }
     }
 }
@@ -1369,6 +1842,10 @@ impl Node for DefaultMatchArm {
     fn children(&mut self) -> Vec<&mut dyn Node> {
         vec![&mut self.body]
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        vec![&self.body]
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -1389,6 +1866,16 @@ impl Node for MatchArm {
         children.push(&mut self.body);
         children
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        let mut children: Vec<&dyn Node> = self
+            .conditions
+            .iter()
+            .map(|condition| condition as &dyn Node)
+            .collect();
+        children.push(&self.body);
+        children
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -1436,6 +1923,10 @@ impl Node for ExpressionStringPart {
     fn children(&mut self) -> Vec<&mut dyn Node> {
         vec![self.expression.as_mut()]
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        vec![self.expression.as_ref()]
+    }
 }
 
 impl Node for StringPart {
@@ -1445,6 +1936,13 @@ impl Node for StringPart {
             StringPart::Expression(part) => vec![part],
         }
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        match self {
+            StringPart::Literal(part) => vec![part],
+            StringPart::Expression(part) => vec![part],
+        }
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -1498,6 +1996,29 @@ impl Node for ArrayItem {
             } => vec![key, value],
         }
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        match self {
+            ArrayItem::Skipped => vec![],
+            ArrayItem::Value { value } => vec![value],
+            ArrayItem::ReferencedValue {
+                ampersand: _,
+                value,
+            } => vec![value],
+            ArrayItem::SpreadValue { ellipsis: _, value } => vec![value],
+            ArrayItem::KeyValue {
+                key,
+                double_arrow: _,
+                value,
+            } => vec![key, value],
+            ArrayItem::ReferencedKeyValue {
+                key,
+                double_arrow: _,
+                ampersand: _,
+                value,
+            } => vec![key, value],
+        }
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -1526,4 +2047,16 @@ impl Node for ListEntry {
             } => vec![key, value],
         }
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        match self {
+            ListEntry::Skipped => vec![],
+            ListEntry::Value { value } => vec![value],
+            ListEntry::KeyValue {
+                key,
+                double_arrow: _,
+                value,
+            } => vec![key, value],
+        }
+    }
 }
@@ -4,15 +4,16 @@ use serde::Serialize;
 
 use crate::lexer::token::Span;
 use crate::node::Node;
-use crate::parser::ast::identifiers::SimpleIdentifier;
+use crate::parser::ast::identifiers::Name;
+use crate::parser::ast::Ending;
 use crate::parser::ast::Statement;
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
 
 pub struct UnbracedNamespace {
     pub start: Span,                // `namespace`
-    pub name: SimpleIdentifier,     // `Foo`
-    pub end: Span,                  // `;`
+    pub name: Name,                 // `Foo`
+    pub end: Ending,                // `;` or `?>`
     pub statements: Vec<Statement>, // `*statements*`
 }
 
@@ -27,14 +28,25 @@ impl Node for UnbracedNamespace {
         );
         children
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        let mut children = vec![&self.name as &dyn Node];
+        children.extend(
+            self.statements
+                .iter()
+                .map(|s| s as &dyn Node)
+                .collect::<Vec<&dyn Node>>(),
+        );
+        children
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
 
 pub struct BracedNamespace {
-    pub namespace: Span,                // `namespace`
-    pub name: Option<SimpleIdentifier>, // `Foo`
-    pub body: BracedNamespaceBody,      // `{ *statements* }`
+    pub namespace: Span,           // `namespace`
+    pub name: Option<Name>,        // `Foo`
+    pub body: BracedNamespaceBody, // `{ *statements* }`
 }
 
 impl Node for BracedNamespace {
@@ -46,6 +58,15 @@ impl Node for BracedNamespace {
         children.push(&mut self.body);
         children
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        let mut children: Vec<&dyn Node> = vec![];
+        if let Some(name) = &self.name {
+            children.push(name);
+        }
+        children.push(&self.body);
+        children
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -63,6 +84,10 @@ impl Node for BracedNamespaceBody {
             .map(|s| s as &mut dyn Node)
             .collect()
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        self.statements.iter().map(|s| s as &dyn Node).collect()
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -79,4 +104,11 @@ impl Node for NamespaceStatement {
             NamespaceStatement::Braced(namespace) => vec![namespace],
         }
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        match self {
+            NamespaceStatement::Unbraced(namespace) => vec![namespace],
+            NamespaceStatement::Braced(namespace) => vec![namespace],
+        }
+    }
 }
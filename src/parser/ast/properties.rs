@@ -5,6 +5,7 @@ use serde::Serialize;
 use crate::lexer::token::Span;
 use crate::node::Node;
 use crate::parser::ast::attributes::AttributeGroup;
+use crate::parser::ast::comments::CommentGroup;
 use crate::parser::ast::data_type::Type;
 use crate::parser::ast::modifiers::PropertyModifierGroup;
 use crate::parser::ast::variables::SimpleVariable;
@@ -13,6 +14,7 @@ use crate::parser::ast::Expression;
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
 
 pub struct Property {
+    pub comments: CommentGroup,
     pub attributes: Vec<AttributeGroup>,
     #[serde(flatten)]
     pub modifiers: PropertyModifierGroup,
@@ -35,11 +37,26 @@ impl Node for Property {
         );
         children
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        let mut children: Vec<&dyn Node> = vec![];
+        if let Some(r#type) = &self.r#type {
+            children.push(r#type);
+        }
+        children.extend(
+            self.entries
+                .iter()
+                .map(|e| e as &dyn Node)
+                .collect::<Vec<&dyn Node>>(),
+        );
+        children
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
 
 pub struct VariableProperty {
+    pub comments: CommentGroup,
     pub attributes: Vec<AttributeGroup>,
     pub r#type: Option<Type>,
     pub entries: Vec<PropertyEntry>,
@@ -60,6 +77,20 @@ impl Node for VariableProperty {
         );
         children
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        let mut children: Vec<&dyn Node> = vec![];
+        if let Some(r#type) = &self.r#type {
+            children.push(r#type);
+        }
+        children.extend(
+            self.entries
+                .iter()
+                .map(|e| e as &dyn Node)
+                .collect::<Vec<&dyn Node>>(),
+        );
+        children
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -84,6 +115,15 @@ impl Node for PropertyEntry {
             } => vec![variable, value],
         }
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        match self {
+            PropertyEntry::Uninitialized { variable } => vec![variable],
+            PropertyEntry::Initialized {
+                variable, value, ..
+            } => vec![variable, value],
+        }
+    }
 }
 
 impl PropertyEntry {
@@ -5,11 +5,13 @@ use serde::Serialize;
 use crate::lexer::token::Span;
 use crate::node::Node;
 use crate::parser::ast::attributes::AttributeGroup;
+use crate::parser::ast::comments::CommentGroup;
 use crate::parser::ast::constant::ClassishConstant;
 use crate::parser::ast::functions::AbstractConstructor;
 use crate::parser::ast::functions::AbstractMethod;
 use crate::parser::ast::functions::ConcreteConstructor;
 use crate::parser::ast::functions::ConcreteMethod;
+use crate::parser::ast::identifiers::Name;
 use crate::parser::ast::identifiers::SimpleIdentifier;
 use crate::parser::ast::modifiers::VisibilityModifier;
 use crate::parser::ast::properties::Property;
@@ -41,6 +43,19 @@ impl Node for TraitMember {
             TraitMember::ConcreteConstructor(constructor) => vec![constructor],
         }
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        match self {
+            TraitMember::Constant(constant) => vec![constant],
+            TraitMember::TraitUsage(usage) => vec![usage],
+            TraitMember::Property(property) => vec![property],
+            TraitMember::VariableProperty(property) => vec![property],
+            TraitMember::AbstractMethod(method) => vec![method],
+            TraitMember::AbstractConstructor(constructor) => vec![constructor],
+            TraitMember::ConcreteMethod(method) => vec![method],
+            TraitMember::ConcreteConstructor(constructor) => vec![constructor],
+        }
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -58,11 +73,19 @@ impl Node for TraitBody {
             .map(|member| member as &mut dyn Node)
             .collect()
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        self.members
+            .iter()
+            .map(|member| member as &dyn Node)
+            .collect()
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
 
 pub struct TraitStatement {
+    pub comments: CommentGroup,
     pub r#trait: Span,
     pub name: SimpleIdentifier,
     pub attributes: Vec<AttributeGroup>,
@@ -73,13 +96,17 @@ impl Node for TraitStatement {
     fn children(&mut self) -> Vec<&mut dyn Node> {
         vec![&mut self.name, &mut self.body]
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        vec![&self.name, &self.body]
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
 
 pub struct TraitUsage {
     pub r#use: Span,
-    pub traits: Vec<SimpleIdentifier>,
+    pub traits: Vec<Name>,
     pub adaptations: Vec<TraitUsageAdaptation>,
 }
 
@@ -87,25 +114,29 @@ impl Node for TraitUsage {
     fn children(&mut self) -> Vec<&mut dyn Node> {
         self.traits.iter_mut().map(|t| t as &mut dyn Node).collect()
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        self.traits.iter().map(|t| t as &dyn Node).collect()
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(tag = "type", content = "value")]
 pub enum TraitUsageAdaptation {
     Alias {
-        r#trait: Option<SimpleIdentifier>,
+        r#trait: Option<Name>,
         method: SimpleIdentifier,
         alias: SimpleIdentifier,
         visibility: Option<VisibilityModifier>,
     },
     Visibility {
-        r#trait: Option<SimpleIdentifier>,
+        r#trait: Option<Name>,
         method: SimpleIdentifier,
         visibility: VisibilityModifier,
     },
     Precedence {
-        r#trait: Option<SimpleIdentifier>,
+        r#trait: Option<Name>,
         method: SimpleIdentifier,
-        insteadof: Vec<SimpleIdentifier>,
+        insteadof: Vec<Name>,
     },
 }
@@ -4,7 +4,7 @@ use serde::Serialize;
 
 use crate::lexer::token::Span;
 use crate::node::Node;
-use crate::parser::ast::identifiers::SimpleIdentifier;
+use crate::parser::ast::identifiers::Name;
 use crate::parser::ast::Block;
 
 use super::variables::SimpleVariable;
@@ -12,8 +12,8 @@ use super::variables::SimpleVariable;
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(tag = "type", content = "value")]
 pub enum CatchType {
-    Identifier { identifier: SimpleIdentifier },
-    Union { identifiers: Vec<SimpleIdentifier> },
+    Identifier { identifier: Name },
+    Union { identifiers: Vec<Name> },
 }
 
 impl Node for CatchType {
@@ -25,6 +25,15 @@ impl Node for CatchType {
             }
         }
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        match self {
+            CatchType::Identifier { identifier } => vec![identifier],
+            CatchType::Union { identifiers } => {
+                identifiers.iter().map(|i| i as &dyn Node).collect()
+            }
+        }
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -48,6 +57,17 @@ impl Node for TryStatement {
         }
         children
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        let mut children: Vec<&dyn Node> = vec![&self.body];
+        for catch in &self.catches {
+            children.push(catch);
+        }
+        if let Some(finally) = &self.finally {
+            children.push(finally);
+        }
+        children
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -69,6 +89,15 @@ impl Node for CatchBlock {
         children.push(&mut self.body as &mut dyn Node);
         children
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        let mut children = vec![&self.types as &dyn Node];
+        if let Some(var) = &self.var {
+            children.push(var as &dyn Node);
+        }
+        children.push(&self.body as &dyn Node);
+        children
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -83,4 +112,8 @@ impl Node for FinallyBlock {
     fn children(&mut self) -> Vec<&mut dyn Node> {
         vec![&mut self.body as &mut dyn Node]
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        vec![&self.body as &dyn Node]
+    }
 }
@@ -23,12 +23,25 @@ impl<T> CommaSeparated<T> {
     pub fn iter_mut(&mut self) -> IterMut<'_, T> {
         self.inner.iter_mut()
     }
+
+    /// The span of the comma following the last item, if there is one.
+    pub fn trailing_comma(&self) -> Option<Span> {
+        if !self.inner.is_empty() && self.commas.len() == self.inner.len() {
+            self.commas.last().copied()
+        } else {
+            None
+        }
+    }
 }
 
 impl<T: Node> Node for CommaSeparated<T> {
     fn children(&mut self) -> Vec<&mut dyn Node> {
         self.inner.iter_mut().map(|x| x as &mut dyn Node).collect()
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        self.inner.iter().map(|x| x as &dyn Node).collect()
+    }
 }
 
 impl<T> IntoIterator for CommaSeparated<T> {
@@ -25,6 +25,14 @@ impl Node for Variable {
             Variable::BracedVariableVariable(variable) => variable.children(),
         }
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        match self {
+            Variable::SimpleVariable(variable) => variable.children_ref(),
+            Variable::VariableVariable(variable) => variable.children_ref(),
+            Variable::BracedVariableVariable(variable) => variable.children_ref(),
+        }
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -49,6 +57,10 @@ impl Node for VariableVariable {
     fn children(&mut self) -> Vec<&mut dyn Node> {
         vec![self.variable.as_mut()]
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        vec![self.variable.as_ref()]
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -63,6 +75,10 @@ impl Node for BracedVariableVariable {
     fn children(&mut self) -> Vec<&mut dyn Node> {
         vec![self.variable.as_mut()]
     }
+
+    fn children_ref(&self) -> Vec<&dyn Node> {
+        vec![self.variable.as_ref()]
+    }
 }
 
 impl Display for SimpleVariable {
@@ -0,0 +1,62 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use std::fmt::Display;
+
+/// Configuration used to alter the behaviour of the parser.
+///
+/// All options are disabled by default, in which case the parser
+/// produces an AST that mirrors the source code exactly, and accepts
+/// syntax from the latest supported PHP version.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct ParserConfig {
+    /// Fold concatenations of adjacent literal strings, e.g. `'a' . 'b' . 'c'`,
+    /// into a single literal string.
+    pub fold_constant_strings: bool,
+
+    /// Keep line, hash-mark and block comments found between statements,
+    /// as `Statement::Comment`s, instead of discarding them.
+    pub preserve_comments: bool,
+
+    /// Check that every `goto` targets a label defined within the same
+    /// function, and that labels are not defined more than once.
+    pub validate_gotos: bool,
+
+    /// The PHP version being targeted. Syntax introduced in a later version
+    /// is reported as an error.
+    pub php_version: PhpVersion,
+}
+
+/// A version of PHP that the parser can target.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Default, Deserialize, Serialize)]
+pub enum PhpVersion {
+    #[serde(rename = "7.4")]
+    Php74,
+    #[serde(rename = "8.0")]
+    Php80,
+    #[serde(rename = "8.1")]
+    Php81,
+    #[serde(rename = "8.2")]
+    Php82,
+    #[serde(rename = "8.3")]
+    Php83,
+    #[default]
+    #[serde(rename = "8.4")]
+    Php84,
+}
+
+impl Display for PhpVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let version = match self {
+            Self::Php74 => "7.4",
+            Self::Php80 => "8.0",
+            Self::Php81 => "8.1",
+            Self::Php82 => "8.2",
+            Self::Php83 => "8.3",
+            Self::Php84 => "8.4",
+        };
+
+        write!(f, "{}", version)
+    }
+}
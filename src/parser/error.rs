@@ -9,14 +9,16 @@ use crate::lexer::error::SyntaxError;
 use crate::lexer::token::{Span, Token, TokenKind};
 use crate::parser::ast::attributes::AttributeGroup;
 use crate::parser::ast::data_type::Type;
+use crate::parser::ast::literals::LiteralInteger;
 use crate::parser::ast::modifiers::PromotedPropertyModifier;
 use crate::parser::ast::Program;
+use crate::parser::config::PhpVersion;
 
 use super::ast::identifiers::SimpleIdentifier;
 use super::ast::variables::SimpleVariable;
 use super::state::State;
 
-pub type ParseResult<T> = Result<T, ParseError>;
+pub type ParseResult<T> = Result<T, Box<ParseError>>;
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(tag = "type")]
@@ -40,6 +42,9 @@ pub struct ParseError {
     pub span: Span,
     pub annotations: Vec<ParseErrorAnnotation>,
     pub note: Option<String>,
+    /// The tokens, or kinds of tokens, that would have been accepted
+    /// where this error occurred, if known.
+    pub expected: Vec<String>,
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Deserialize, Serialize, JsonSchema)]
@@ -74,6 +79,7 @@ impl ParseError {
             span,
             annotations: Vec::new(),
             note: None,
+            expected: Vec::new(),
         }
     }
 
@@ -105,6 +111,12 @@ impl ParseError {
         self
     }
 
+    pub fn expecting(mut self, expected: Vec<String>) -> Self {
+        self.expected = expected;
+
+        self
+    }
+
     pub fn report<'a>(
         &self,
         source: &'a str,
@@ -195,6 +207,7 @@ pub fn unexpected_token(expected: Vec<String>, found: &Token) -> ParseError {
         };
     }
 
+    let expecting = expected.clone();
     let expected: Vec<String> = expected
         .iter()
         .map(|s| {
@@ -225,9 +238,57 @@ pub fn unexpected_token(expected: Vec<String>, found: &Token) -> ParseError {
         found.span.position,
         found.value.len(),
     )
+    .expecting(expecting)
+}
+
+pub fn unexpected_closing_delimiter(found: &Token) -> ParseError {
+    let opening = match found.kind {
+        TokenKind::RightParen => "(",
+        TokenKind::RightBracket => "[",
+        _ => "{",
+    };
+
+    ParseError::new(
+        "E070".to_string(),
+        format!(
+            "unexpected token `{}` at the top level of the file",
+            found.value
+        ),
+        found.span,
+    )
+    .error("try removing this", found.span.position, found.value.len())
+    .note(format!("there is no open `{}` for this to close", opening))
+}
+
+/// `short` and `nested_short` tell whether the outer and nested ternaries are
+/// short, e.g. `a ?: b ? c : d` is a short ternary nesting a full one.
+pub fn unparenthesized_nested_ternary(
+    short: bool,
+    nested_short: bool,
+    operator: &str,
+    span: Span,
+) -> ParseError {
+    let (pattern, left, right) = match (short, nested_short) {
+        (true, _) => ("a ?: b ? c : d", "(a ?: b) ? c : d", "a ?: (b ? c : d)"),
+        (false, true) => ("a ? b : c ?: d", "(a ? b : c) ?: d", "a ? b : (c ?: d)"),
+        (false, false) => (
+            "a ? b : c ? d : e",
+            "(a ? b : c) ? d : e",
+            "a ? b : (c ? d : e)",
+        ),
+    };
+
+    ParseError::new(
+        "E071".to_string(),
+        format!("unparenthesized `{}` is not supported", pattern),
+        span,
+    )
+    .error("nested ternary operator", span.position, operator.len())
+    .note(format!("use either `{}` or `{}`", left, right))
 }
 
 pub fn unexpected_identifier(expected: Vec<String>, found: String, span: Span) -> ParseError {
+    let expecting = expected.clone();
     let length = expected.len();
     let expected = if length >= 2 {
         let (left, right) = expected.split_at(length - 1);
@@ -250,6 +311,7 @@ pub fn unexpected_identifier(expected: Vec<String>, found: String, span: Span) -
         span.position,
         found.len(),
     )
+    .expecting(expecting)
 }
 
 pub fn multiple_modifiers(modifier: String, first: Span, second: Span) -> ParseError {
@@ -595,6 +657,16 @@ pub fn modifier_cannot_be_used_for_class_method(
     .note("only `public`, `protected`, `private`, `final`, `static`, and `abstract` modifiers can be used on class methods")
 }
 
+pub fn modifier_cannot_be_used_for_enum_case(modifier: String, modifier_span: Span) -> ParseError {
+    ParseError::new(
+        "E069",
+        format!("cannot use '{}' as an enum case modifier", modifier),
+        modifier_span,
+    )
+    .error("try removing this", modifier_span.position, modifier.len())
+    .note("enum cases cannot have modifiers, they are implicitly public and final")
+}
+
 pub fn modifier_cannot_be_used_for_enum_method(
     modifier: String,
     modifier_span: Span,
@@ -850,6 +922,7 @@ pub fn missing_item_definition_after_attributes(
         span: current.span,
         annotations,
         note: None,
+        expected: vec![],
     }
 }
 
@@ -972,6 +1045,273 @@ pub fn argument_is_required(span: Span, current_span: Span) -> ParseError {
     )
 }
 
+pub fn duplicate_catch_type(first: &SimpleIdentifier, second: &SimpleIdentifier) -> ParseError {
+    ParseError::new(
+        "E052".to_string(),
+        format!("type `{}` is already caught by this catch block", second),
+        second.span,
+    )
+    .highlight(first.span.position, first.value.len())
+    .error(
+        "try removing this type",
+        second.span.position,
+        second.value.len(),
+    )
+}
+
+pub fn union_and_intersection_types_mixed(span: Span) -> ParseError {
+    ParseError::new(
+        "E053".to_string(),
+        "cannot mix union and intersection types without parentheses",
+        span,
+    )
+    .error(
+        "try grouping the intersection type using parentheses",
+        span.position,
+        1,
+    )
+}
+
+pub fn union_type_grouped_in_intersection(span: Span) -> ParseError {
+    ParseError::new(
+        "E054".to_string(),
+        "only intersection types can be grouped using parentheses",
+        span,
+    )
+    .error("try removing this", span.position, 1)
+}
+
+pub fn bottom_type_used_in_parameter(ty: &Type) -> ParseError {
+    let type_string = ty.to_string();
+    let type_span = ty.first_span();
+
+    ParseError::new(
+        "E055".to_string(),
+        format!("parameter cannot have type `{}`", type_string),
+        type_span,
+    )
+    .error(
+        "try using a different type",
+        type_span.position,
+        type_string.len(),
+    )
+    .note("`void` and `never` can only be used as return types")
+}
+
+pub fn variadic_parameter_not_last(ellipsis: Span) -> ParseError {
+    ParseError::new(
+        "E056".to_string(),
+        "only the last parameter can be variadic",
+        ellipsis,
+    )
+    .error(
+        "try removing this variadic declaration",
+        ellipsis.position,
+        3,
+    )
+}
+
+pub fn cannot_increment_or_decrement_non_writable_value(span: Span) -> ParseError {
+    ParseError::new(
+        "E057".to_string(),
+        "cannot increment or decrement a non-writable value",
+        span,
+    )
+    .error("try removing this", span.position, 2)
+}
+
+pub fn abstract_method_with_body(
+    state: &mut State,
+    class: Option<&SimpleIdentifier>,
+    method: &SimpleIdentifier,
+    span: Span,
+) -> ParseError {
+    ParseError::new(
+        "E058".to_string(),
+        format!(
+            "abstract method `{}::{}` cannot have a body",
+            class
+                .map(|c| state.named(c))
+                .unwrap_or_else(|| "anonymous@class".to_string()),
+            method
+        ),
+        span,
+    )
+    .error("try replacing this body with `;`", span.position, 1)
+}
+
+pub fn interface_method_with_body(
+    state: &mut State,
+    interface: Option<&SimpleIdentifier>,
+    method: &SimpleIdentifier,
+    span: Span,
+) -> ParseError {
+    ParseError::new(
+        "E072".to_string(),
+        format!(
+            "interface method `{}::{}` cannot have a body",
+            interface
+                .map(|i| state.named(i))
+                .unwrap_or_else(|| "anonymous@interface".to_string()),
+            method
+        ),
+        span,
+    )
+    .error("try replacing this body with `;`", span.position, 1)
+    .note("interface methods are always abstract")
+}
+
+pub fn missing_method_body(
+    state: &mut State,
+    class: Option<&SimpleIdentifier>,
+    method: &SimpleIdentifier,
+    span: Span,
+) -> ParseError {
+    ParseError::new(
+        "E059".to_string(),
+        format!(
+            "non-abstract method `{}::{}` must have a body",
+            class
+                .map(|c| state.named(c))
+                .unwrap_or_else(|| "anonymous@class".to_string()),
+            method
+        ),
+        span,
+    )
+    .error("try replacing this with a body", span.position, 1)
+}
+
+pub fn yield_outside_function(span: Span) -> ParseError {
+    ParseError::new(
+        "E060".to_string(),
+        "cannot use `yield` outside of a function",
+        span,
+    )
+    .error("try moving this into a function", span.position, 5)
+}
+
+pub fn this_outside_object_context(span: Span) -> ParseError {
+    ParseError::new(
+        "E061".to_string(),
+        "cannot use `$this` outside of an object context",
+        span,
+    )
+    .error("`$this` is not available here", span.position, 5)
+}
+
+pub fn switch_statement_has_multiple_default_cases(first: Span, second: Span) -> ParseError {
+    ParseError::new(
+        "E062".to_string(),
+        "switch statement cannot have more than one default case",
+        second,
+    )
+    .highlight(first.position, "default".len())
+    .error("try removing this case", second.position, "default".len())
+}
+
+pub fn feature_not_available(
+    feature: &str,
+    required: PhpVersion,
+    targeted: PhpVersion,
+    span: Span,
+    length: usize,
+) -> ParseError {
+    ParseError::new(
+        "E063".to_string(),
+        format!("{} are only available from PHP {}", feature, required),
+        span,
+    )
+    .error(
+        format!("not available in PHP {}", targeted),
+        span.position,
+        length,
+    )
+    .note(format!(
+        "the parser is configured to target PHP {}, try raising the targeted version",
+        targeted
+    ))
+}
+
+pub fn goto_to_undefined_label(label: &[u8], span: Span) -> ParseError {
+    ParseError::new(
+        "E064".to_string(),
+        format!(
+            "`goto` to undefined label `{}`",
+            String::from_utf8_lossy(label)
+        ),
+        span,
+    )
+    .error(
+        "this label is not defined in the current function",
+        span.position,
+        label.len(),
+    )
+}
+
+pub fn label_already_defined(label: &[u8], first: Span, second: Span) -> ParseError {
+    ParseError::new(
+        "E065".to_string(),
+        format!(
+            "label `{}` is already defined",
+            String::from_utf8_lossy(label)
+        ),
+        second,
+    )
+    .highlight(first.position, label.len())
+    .error("try renaming this label", second.position, label.len())
+}
+
+pub fn invalid_loop_level(keyword: &TokenKind, found: &Token) -> ParseError {
+    ParseError::new(
+        "E066".to_string(),
+        format!("`{}` level must be a positive integer", keyword),
+        found.span,
+    )
+    .error(
+        "try using an integer literal greater than 0",
+        found.span.position,
+        found.value.len(),
+    )
+}
+
+pub fn loop_control_outside_loop(keyword: &TokenKind, span: Span) -> ParseError {
+    ParseError::new(
+        "E067".to_string(),
+        format!("`{}` not in the `loop` or `switch` context", keyword),
+        span,
+    )
+    .error(
+        "try removing this statement",
+        span.position,
+        keyword.to_string().len(),
+    )
+}
+
+pub fn loop_level_exceeds_depth(
+    keyword: &TokenKind,
+    level: &LiteralInteger,
+    depth: usize,
+) -> ParseError {
+    ParseError::new(
+        "E068".to_string(),
+        format!("cannot `{}` {} levels", keyword, level.value),
+        level.span,
+    )
+    .error(
+        format!(
+            "only {} enclosing `loop` or `switch` {}",
+            depth,
+            if depth == 1 {
+                "statement"
+            } else {
+                "statements"
+            }
+        ),
+        level.span.position,
+        level.value.len(),
+    )
+}
+
 impl From<SyntaxError> for ParseError {
     fn from(e: SyntaxError) -> Self {
         Self {
@@ -980,10 +1320,17 @@ impl From<SyntaxError> for ParseError {
             annotations: vec![],
             span: e.span(),
             note: None,
+            expected: vec![],
         }
     }
 }
 
+impl From<SyntaxError> for Box<ParseError> {
+    fn from(e: SyntaxError) -> Self {
+        Box::new(e.into())
+    }
+}
+
 impl Display for ParseError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -1000,6 +1347,43 @@ impl Display for ParseError {
     }
 }
 
+impl std::error::Error for ParseError {}
+
+#[cfg(feature = "diagnostics")]
+impl miette::Diagnostic for ParseError {
+    fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        Some(Box::new(&self.id))
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.note
+            .as_ref()
+            .map(|note| Box::new(note) as Box<dyn Display + 'a>)
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = miette::LabeledSpan> + '_>> {
+        Some(Box::new(self.annotations.iter().map(|annotation| {
+            let label = match annotation.r#type {
+                ParseErrorAnnotationType::Hint => None,
+                ParseErrorAnnotationType::Error => Some(annotation.message.clone()),
+            };
+
+            miette::LabeledSpan::new(label, annotation.position, annotation.length)
+        })))
+    }
+}
+
+#[cfg(feature = "diagnostics")]
+impl ParseErrorStack {
+    /// Convert the errors into `miette` reports, each rendering a snippet of the given source.
+    pub fn diagnostics(&self, source: &str) -> Vec<miette::Report> {
+        self.errors
+            .iter()
+            .map(|error| miette::Report::new(error.clone()).with_source_code(source.to_string()))
+            .collect()
+    }
+}
+
 impl Display for ParseErrorStack {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         for error in &self.errors {
@@ -1009,3 +1393,35 @@ impl Display for ParseErrorStack {
         Ok(())
     }
 }
+
+#[cfg(all(test, feature = "diagnostics"))]
+mod tests {
+    use miette::NarratableReportHandler;
+
+    // the narratable handler is the one `miette` uses when `NO_COLOR` is set.
+    #[test]
+    fn test_diagnostic_renders_labelled_snippet() {
+        let code = "<?php\n\nfunction foo(...$a, $b) {}\n";
+        let stack = crate::parse(code).unwrap_err();
+        let reports = stack.diagnostics(code);
+
+        let mut output = String::new();
+        NarratableReportHandler::new()
+            .render_report(&mut output, reports[0].as_ref())
+            .unwrap();
+
+        assert_eq!(
+            output,
+            concat!(
+                "[E056] Error: only the last parameter can be variadic on line 3 column 14\n",
+                "    Diagnostic severity: error\n",
+                "Begin snippet starting at line 2, column 1\n",
+                "\n",
+                "snippet line 2: \n",
+                "snippet line 3: function foo(...$a, $b) {}\n",
+                "    label at line 3, columns 14 to 16: try removing this variadic declaration\n",
+                "diagnostic code: E056\n",
+            )
+        );
+    }
+}
@@ -15,15 +15,16 @@ use crate::parser::ast::operators::BitwiseOperationExpression;
 use crate::parser::ast::operators::ComparisonOperationExpression;
 use crate::parser::ast::operators::LogicalOperationExpression;
 use crate::parser::ast::{
-    ArrayIndexExpression, CoalesceExpression, ConcatExpression, ConstantFetchExpression,
-    Expression, FunctionCallExpression, FunctionClosureCreationExpression, InstanceofExpression,
-    MagicConstantExpression, MethodCallExpression, MethodClosureCreationExpression,
-    NullsafeMethodCallExpression, NullsafePropertyFetchExpression, PropertyFetchExpression,
-    ReferenceExpression, ShortTernaryExpression, StaticMethodCallExpression,
-    StaticMethodClosureCreationExpression, StaticPropertyFetchExpression,
-    StaticVariableMethodCallExpression, StaticVariableMethodClosureCreationExpression,
-    TernaryExpression,
+    ArrayIndexExpression, ClassNameExpression, CoalesceExpression, ConcatExpression,
+    ConstantFetchExpression, Expression, FunctionCallExpression, FunctionClosureCreationExpression,
+    InstanceofExpression, MagicConstantExpression, MethodCallExpression,
+    MethodClosureCreationExpression, NullsafeMethodCallExpression, NullsafePropertyFetchExpression,
+    PropertyFetchExpression, ReferenceExpression, ShortTernaryExpression,
+    StaticMethodCallExpression, StaticMethodClosureCreationExpression,
+    StaticPropertyFetchExpression, StaticVariableMethodCallExpression,
+    StaticVariableMethodClosureCreationExpression, TernaryExpression,
 };
+use crate::parser::config::PhpVersion;
 use crate::parser::error;
 use crate::parser::error::ParseResult;
 use crate::parser::internal::arrays;
@@ -86,20 +87,32 @@ fn for_precedence(state: &mut State, precedence: Precedence) -> ParseResult<Expr
             break;
         }
 
-        if is_postfix(kind) {
-            let lpred = Precedence::postfix(kind);
-
+        if let Some(lpred) = Precedence::postfix(kind) {
             if lpred < precedence {
                 break;
             }
 
+            // e.g. `new Foo()->bar()`, as opposed to `(new Foo())->bar()`.
+            if let Expression::New(NewExpression { arguments, .. }) = &left {
+                if lpred >= Precedence::CallDim {
+                    if arguments.is_none() {
+                        return Err(Box::new(error::unexpected_token(vec![], current)));
+                    }
+
+                    state.require(
+                        PhpVersion::Php84,
+                        "unparenthesized `new` chains",
+                        span,
+                        current.value.len(),
+                    );
+                }
+            }
+
             left = postfix(state, left, kind)?;
             continue;
         }
 
-        if is_infix(kind) {
-            let rpred = Precedence::infix(kind);
-
+        if let Some(rpred) = Precedence::infix(kind) {
             if rpred < precedence {
                 break;
             }
@@ -109,7 +122,7 @@ fn for_precedence(state: &mut State, precedence: Precedence) -> ParseResult<Expr
             }
 
             if rpred == precedence && matches!(rpred.associativity(), Some(Associativity::Non)) {
-                return Err(error::unexpected_token(vec![], current));
+                return Err(Box::new(error::unexpected_token(vec![], current)));
             }
 
             state.stream.next();
@@ -125,6 +138,7 @@ fn for_precedence(state: &mut State, precedence: Precedence) -> ParseResult<Expr
                         state.stream.next();
 
                         let r#else = create(state)?;
+                        ensure_ternary_is_not_nested(state, true, &r#else);
 
                         Expression::Ternary(TernaryExpression {
                             condition: Box::new(left),
@@ -134,9 +148,13 @@ fn for_precedence(state: &mut State, precedence: Precedence) -> ParseResult<Expr
                             r#else: Box::new(r#else),
                         })
                     } else {
+                        // both branches are parsed at the lowest precedence, so that
+                        // assignments are allowed as branch values, e.g:
+                        // `$cond ? $a = 1 : $b = 2` is `$cond ? ($a = 1) : ($b = 2)`
                         let then = create(state)?;
                         let colon = utils::skip_colon(state)?;
                         let r#else = create(state)?;
+                        ensure_ternary_is_not_nested(state, false, &r#else);
 
                         Expression::Ternary(TernaryExpression {
                             condition: Box::new(left),
@@ -149,6 +167,8 @@ fn for_precedence(state: &mut State, precedence: Precedence) -> ParseResult<Expr
                 }
                 TokenKind::QuestionColon => {
                     let r#else = create(state)?;
+                    ensure_ternary_is_not_nested(state, true, &r#else);
+
                     Expression::ShortTernary(ShortTernaryExpression {
                         condition: Box::new(left),
                         question_colon: span,
@@ -158,9 +178,12 @@ fn for_precedence(state: &mut State, precedence: Precedence) -> ParseResult<Expr
                 TokenKind::Equals if op.kind == TokenKind::Ampersand => {
                     state.stream.next();
 
-                    // FIXME: You should only be allowed to assign a referencable variable,
-                    //        here, not any old expression.
                     let right = Box::new(for_precedence(state, rpred)?);
+                    if !right.is_referencable() {
+                        state.record(error::cannot_assign_reference_to_non_referencable_value(
+                            op.span,
+                        ));
+                    }
 
                     Expression::AssignmentOperation(AssignmentOperationExpression::Assign {
                         left: Box::new(left),
@@ -228,6 +251,25 @@ fn for_precedence(state: &mut State, precedence: Precedence) -> ParseResult<Expr
                         ))),
                     })
                 }
+                TokenKind::Dot if state.config.fold_constant_strings => {
+                    let right = for_precedence(state, rpred)?;
+
+                    match (left, right) {
+                        (
+                            Expression::Literal(Literal::String(mut left)),
+                            Expression::Literal(Literal::String(right)),
+                        ) => {
+                            left.value.extend_from_slice(&right.value);
+
+                            Expression::Literal(Literal::String(left))
+                        }
+                        (left, right) => Expression::Concat(ConcatExpression {
+                            left: Box::new(left),
+                            dot: span,
+                            right: Box::new(right),
+                        }),
+                    }
+                }
                 _ => {
                     let left = Box::new(left);
                     let right = Box::new(for_precedence(state, rpred)?);
@@ -539,7 +581,10 @@ fn for_precedence(state: &mut State, precedence: Precedence) -> ParseResult<Expr
 
 fn left(state: &mut State, precedence: &Precedence) -> ParseResult<Expression> {
     if state.stream.is_eof() {
-        return Err(error::unexpected_token(vec![], state.stream.current()));
+        return Err(Box::new(error::unexpected_token(
+            vec![],
+            state.stream.current(),
+        )));
     }
 
     attributes(state, precedence)
@@ -590,10 +635,10 @@ expressions! {
             TokenKind::Function => functions::anonymous_function(state),
             TokenKind::Fn => functions::arrow_function(state),
             _ => {
-                Err(error::missing_item_definition_after_attributes(
+                Err(Box::new(error::missing_item_definition_after_attributes(
                     &state.attributes,
                     current,
-                ))
+                )))
             }
         }
     })
@@ -618,12 +663,20 @@ expressions! {
         functions::anonymous_function(state)
     })
 
-    #[before(empty), current(TokenKind::Eval), peek(TokenKind::LeftParen)]
+    #[before(empty), current(TokenKind::Eval)]
     eval({
         let eval = state.stream.current().span;
         state.stream.next();
 
-        let argument = Box::new(parameters::single_argument(state, true, true).unwrap()?);
+        // `eval` is a language construct, so the parentheses are not optional.
+        if state.stream.current().kind != TokenKind::LeftParen {
+            return expected_token_err!("(", state);
+        }
+
+        let argument = match parameters::single_argument(state, true, true) {
+            Some(argument) => Box::new(argument?),
+            None => return expected_token_err!(")", state),
+        };
 
         Ok(Expression::Eval(EvalExpression { eval, argument }))
     })
@@ -740,6 +793,11 @@ expressions! {
 
     #[before(clone), current(TokenKind::Yield)]
     r#yield({
+        let span = state.stream.current().span;
+        if !state.in_function() {
+            state.record(error::yield_outside_function(span));
+        }
+
         state.stream.next();
         if state.stream.current().kind == TokenKind::SemiColon || state.stream.current().kind == TokenKind::RightParen {
             Ok(Expression::Yield(YieldExpression {
@@ -955,6 +1013,10 @@ expressions! {
     new({
         let new = state.stream.current().span;
 
+        if state.initializer {
+            state.require(PhpVersion::Php81, "new in initializers", new, 3);
+        }
+
         state.stream.next();
 
         if state.stream.current().kind == TokenKind::Class || state.stream.current().kind == TokenKind::Attribute {
@@ -991,7 +1053,11 @@ expressions! {
 
                 Expression::Identifier(Identifier::SimpleIdentifier(SimpleIdentifier { span, value: "from".into() }))
             }
-            _ => clone_or_new_precedence(state)?,
+            _ => {
+                let target = clone_or_new_precedence(state)?;
+
+                new_target(state, target)?
+            }
         };
 
         let arguments = if state.stream.current().kind == TokenKind::LeftParen {
@@ -1130,6 +1196,10 @@ expressions! {
         state.stream.next();
 
         let right = Box::new(for_precedence(state, Precedence::Prefix)?);
+        if matches!(op, TokenKind::Decrement | TokenKind::Increment) && !right.is_writable() {
+            state.record(error::cannot_increment_or_decrement_non_writable_value(span));
+        }
+
         let expr = match op {
             TokenKind::Minus => Expression::ArithmeticOperation(ArithmeticOperationExpression::Negative { minus: span, right }),
             TokenKind::Plus => Expression::ArithmeticOperation(ArithmeticOperationExpression::Positive { plus: span, right }),
@@ -1186,10 +1256,34 @@ expressions! {
     })
 }
 
+/// Ternaries cannot be nested in the `else` branch of another ternary without
+/// parentheses, as of PHP 8, because the order of evaluation is ambiguous.
+/// Short ternaries are the exception, so `$a ?: $b ?: $c` is still allowed.
+fn ensure_ternary_is_not_nested(state: &mut State, short: bool, r#else: &Expression) {
+    let (nested_short, operator, span) = match r#else {
+        Expression::Ternary(TernaryExpression { question, then, .. }) => {
+            (matches!(then.as_ref(), Expression::Noop), "?", *question)
+        }
+        Expression::ShortTernary(ShortTernaryExpression { question_colon, .. }) => {
+            (true, "?:", *question_colon)
+        }
+        _ => return,
+    };
+
+    if !(short && nested_short) {
+        state.record(error::unparenthesized_nested_ternary(
+            short,
+            nested_short,
+            operator,
+            span,
+        ));
+    }
+}
+
 fn unexpected_token(state: &mut State, _: &Precedence) -> ParseResult<Expression> {
     let current = state.stream.current();
 
-    Err(error::unexpected_token(vec![], current))
+    Err(Box::new(error::unexpected_token(vec![], current)))
 }
 
 fn postfix(state: &mut State, lhs: Expression, op: &TokenKind) -> ParseResult<Expression> {
@@ -1250,6 +1344,18 @@ fn postfix(state: &mut State, lhs: Expression, op: &TokenKind) -> ParseResult<Ex
 
             let current = state.stream.current();
 
+            // `Foo::class()` is a call to a method named `class`, rather than the class name.
+            if current.kind == TokenKind::Class && state.stream.peek().kind != TokenKind::LeftParen
+            {
+                state.stream.next();
+
+                return Ok(Expression::ClassName(ClassNameExpression {
+                    target: Box::new(lhs),
+                    double_colon: span,
+                    class: current.span,
+                }));
+            }
+
             let property = match current.kind {
                 TokenKind::Variable | TokenKind::Dollar | TokenKind::DollarLeftBrace => {
                     Expression::Variable(variables::dynamic_variable(state)?)
@@ -1268,16 +1374,8 @@ fn postfix(state: &mut State, lhs: Expression, op: &TokenKind) -> ParseResult<Ex
                         end: utils::skip_right_brace(state)?,
                     }))
                 }
-                TokenKind::Class => {
-                    state.stream.next();
-
-                    Expression::Identifier(Identifier::SimpleIdentifier(SimpleIdentifier {
-                        span: current.span,
-                        value: "class".into(),
-                    }))
-                }
                 _ => {
-                    return expected_token_err!(["`{`", "`$`", "an identifier"], state);
+                    return expected_token_err!(["{", "$", "an identifier"], state);
                 }
             };
 
@@ -1368,33 +1466,7 @@ fn postfix(state: &mut State, lhs: Expression, op: &TokenKind) -> ParseResult<Ex
             let span = state.stream.current().span;
             state.stream.next();
 
-            let property = match state.stream.current().kind {
-                TokenKind::Variable | TokenKind::Dollar | TokenKind::DollarLeftBrace => {
-                    Expression::Variable(variables::dynamic_variable(state)?)
-                }
-                _ if identifiers::is_identifier_maybe_reserved(&state.stream.current().kind) => {
-                    Expression::Identifier(Identifier::SimpleIdentifier(
-                        identifiers::identifier_maybe_reserved(state)?,
-                    ))
-                }
-                TokenKind::LeftBrace => {
-                    let start = state.stream.current().span;
-                    state.stream.next();
-
-                    let name = create(state)?;
-
-                    let end = utils::skip_right_brace(state)?;
-
-                    Expression::Identifier(Identifier::DynamicIdentifier(DynamicIdentifier {
-                        start,
-                        expr: Box::new(name),
-                        end,
-                    }))
-                }
-                _ => {
-                    return expected_token_err!(["`{`", "`$`", "an identifier"], state);
-                }
-            };
+            let property = object_property(state)?;
 
             if state.stream.current().kind == TokenKind::LeftParen {
                 if op == &TokenKind::QuestionArrow {
@@ -1457,6 +1529,12 @@ fn postfix(state: &mut State, lhs: Expression, op: &TokenKind) -> ParseResult<Ex
             let span = state.stream.current().span;
             state.stream.next();
 
+            if !lhs.is_writable() {
+                state.record(error::cannot_increment_or_decrement_non_writable_value(
+                    span,
+                ));
+            }
+
             Expression::ArithmeticOperation(ArithmeticOperationExpression::PostIncrement {
                 left: Box::new(lhs),
                 increment: span,
@@ -1466,6 +1544,12 @@ fn postfix(state: &mut State, lhs: Expression, op: &TokenKind) -> ParseResult<Ex
             let span = state.stream.current().span;
             state.stream.next();
 
+            if !lhs.is_writable() {
+                state.record(error::cannot_increment_or_decrement_non_writable_value(
+                    span,
+                ));
+            }
+
             Expression::ArithmeticOperation(ArithmeticOperationExpression::PostDecrement {
                 left: Box::new(lhs),
                 decrement: span,
@@ -1475,67 +1559,89 @@ fn postfix(state: &mut State, lhs: Expression, op: &TokenKind) -> ParseResult<Ex
     })
 }
 
-fn is_infix(t: &TokenKind) -> bool {
-    matches!(
-        t,
-        TokenKind::Pow
-            | TokenKind::RightShiftEquals
-            | TokenKind::LeftShiftEquals
-            | TokenKind::CaretEquals
-            | TokenKind::AmpersandEquals
-            | TokenKind::PipeEquals
-            | TokenKind::PercentEquals
-            | TokenKind::PowEquals
-            | TokenKind::LogicalAnd
-            | TokenKind::LogicalOr
-            | TokenKind::LogicalXor
-            | TokenKind::Spaceship
-            | TokenKind::LeftShift
-            | TokenKind::RightShift
-            | TokenKind::Ampersand
-            | TokenKind::Pipe
-            | TokenKind::Caret
-            | TokenKind::Percent
-            | TokenKind::Instanceof
-            | TokenKind::Asterisk
-            | TokenKind::Slash
-            | TokenKind::Plus
-            | TokenKind::Minus
-            | TokenKind::Dot
-            | TokenKind::LessThan
-            | TokenKind::GreaterThan
-            | TokenKind::LessThanEquals
-            | TokenKind::GreaterThanEquals
-            | TokenKind::DoubleEquals
-            | TokenKind::TripleEquals
-            | TokenKind::BangEquals
-            | TokenKind::BangDoubleEquals
-            | TokenKind::AngledLeftRight
-            | TokenKind::Question
-            | TokenKind::QuestionColon
-            | TokenKind::BooleanAnd
-            | TokenKind::BooleanOr
-            | TokenKind::Equals
-            | TokenKind::PlusEquals
-            | TokenKind::MinusEquals
-            | TokenKind::DotEquals
-            | TokenKind::DoubleQuestionEquals
-            | TokenKind::AsteriskEquals
-            | TokenKind::SlashEquals
-    )
+/// Parse the property name following `->` or `?->`.
+fn object_property(state: &mut State) -> ParseResult<Expression> {
+    Ok(match state.stream.current().kind {
+        TokenKind::Variable | TokenKind::Dollar | TokenKind::DollarLeftBrace => {
+            Expression::Variable(variables::dynamic_variable(state)?)
+        }
+        _ if identifiers::is_identifier_maybe_reserved(&state.stream.current().kind) => {
+            Expression::Identifier(Identifier::SimpleIdentifier(
+                identifiers::identifier_maybe_reserved(state)?,
+            ))
+        }
+        TokenKind::LeftBrace => {
+            let start = state.stream.current().span;
+            state.stream.next();
+
+            let name = create(state)?;
+
+            let end = utils::skip_right_brace(state)?;
+
+            Expression::Identifier(Identifier::DynamicIdentifier(DynamicIdentifier {
+                start,
+                expr: Box::new(name),
+                end,
+            }))
+        }
+        _ => {
+            return expected_token_err!(["{", "$", "an identifier"], state);
+        }
+    })
 }
 
-#[inline(always)]
-fn is_postfix(t: &TokenKind) -> bool {
-    matches!(
-        t,
-        TokenKind::Increment
-            | TokenKind::Decrement
-            | TokenKind::LeftParen
-            | TokenKind::LeftBracket
-            | TokenKind::Arrow
-            | TokenKind::QuestionArrow
-            | TokenKind::DoubleColon
-            | TokenKind::DoubleQuestion
-    )
+/// Parse the property fetches and array accesses following the class of a `new`
+/// expression, e.g. `new $a->b['c']()`, which instantiates the class named by `$a->b['c']`.
+///
+/// Only variables can be dereferenced this way, a class name may only be followed by a
+/// static property fetch, e.g. `new Foo::$bar`.
+fn new_target(state: &mut State, mut target: Expression) -> ParseResult<Expression> {
+    let mut variable = match target {
+        Expression::Variable(_) => true,
+        Expression::Identifier(_) => false,
+        _ => return Ok(target),
+    };
+
+    loop {
+        let current = state.stream.current();
+
+        target = match current.kind {
+            TokenKind::LeftBracket if variable => postfix(state, target, &TokenKind::LeftBracket)?,
+            TokenKind::Arrow | TokenKind::QuestionArrow if variable => {
+                state.stream.next();
+
+                let property = Box::new(object_property(state)?);
+
+                if current.kind == TokenKind::QuestionArrow {
+                    Expression::NullsafePropertyFetch(NullsafePropertyFetchExpression {
+                        target: Box::new(target),
+                        question_arrow: current.span,
+                        property,
+                    })
+                } else {
+                    Expression::PropertyFetch(PropertyFetchExpression {
+                        target: Box::new(target),
+                        arrow: current.span,
+                        property,
+                    })
+                }
+            }
+            TokenKind::DoubleColon
+                if matches!(
+                    state.stream.peek().kind,
+                    TokenKind::Variable | TokenKind::Dollar | TokenKind::DollarLeftBrace
+                ) =>
+            {
+                state.stream.next();
+                variable = true;
+
+                Expression::StaticPropertyFetch(StaticPropertyFetchExpression {
+                    target: Box::new(target),
+                    double_colon: current.span,
+                    property: variables::dynamic_variable(state)?,
+                })
+            }
+            _ => return Ok(target),
+        };
+    }
 }
@@ -21,7 +21,7 @@ pub fn gather_attributes(state: &mut State) -> ParseResult<bool> {
         let start = state.stream.current().span;
         let name = identifiers::full_type_name_including_self(state)?;
         let arguments = if state.stream.current().kind == TokenKind::LeftParen {
-            Some(parameters::argument_list(state)?)
+            Some(state.initializer(parameters::argument_list)?)
         } else {
             None
         };
@@ -1,6 +1,7 @@
 use crate::lexer::token::OpenTagKind;
 use crate::lexer::token::TokenKind;
 use crate::parser;
+use crate::parser::ast::Block;
 use crate::parser::ast::BlockStatement;
 use crate::parser::ast::Statement;
 use crate::parser::error::ParseResult;
@@ -27,17 +28,21 @@ pub fn multiple_statements_until(
 
     let mut current = state.stream.current();
     while &current.kind != until {
-        if let TokenKind::OpenTag(OpenTagKind::Full) = current.kind {
+        comments(state, &mut statements);
+
+        if let TokenKind::OpenTag(OpenTagKind::Full | OpenTagKind::Short) = current.kind {
             state.stream.next();
 
             current = state.stream.current();
             continue;
         }
 
-        statements.push(parser::statement(state)?);
+        statements.push(parser::recoverable(state, parser::statement)?);
         current = state.stream.current();
     }
 
+    trailing_comments(state, &mut statements);
+
     Ok(statements)
 }
 
@@ -49,16 +54,42 @@ pub fn multiple_statements_until_any(
 
     let mut current = state.stream.current();
     while !until.contains(&current.kind) {
-        if let TokenKind::OpenTag(OpenTagKind::Full) = current.kind {
+        comments(state, &mut statements);
+
+        if let TokenKind::OpenTag(OpenTagKind::Full | OpenTagKind::Short) = current.kind {
             state.stream.next();
 
             current = state.stream.current();
             continue;
         }
 
-        statements.push(parser::statement(state)?);
+        statements.push(parser::recoverable(state, parser::statement)?);
         current = state.stream.current();
     }
 
+    trailing_comments(state, &mut statements);
+
     Ok(statements)
 }
+
+/// Push the comments preceding the current token onto the given block,
+/// if the parser has been configured to preserve comments.
+pub fn comments(state: &mut State, statements: &mut Block) {
+    if !state.config.preserve_comments {
+        return;
+    }
+
+    while let Some(comment) = state.stream.take_comment() {
+        statements.push(Statement::Comment(comment));
+    }
+}
+
+/// Push all of the remaining comments onto the given block, including document
+/// comments, as there is no declaration left in the block to attach them to.
+pub fn trailing_comments(state: &mut State, statements: &mut Block) {
+    if !state.config.preserve_comments {
+        return;
+    }
+
+    statements.extend(state.stream.comments().into_iter().map(Statement::Comment));
+}
@@ -8,9 +8,11 @@ use crate::parser::ast::classes::ClassExtends;
 use crate::parser::ast::classes::ClassImplements;
 use crate::parser::ast::classes::ClassMember;
 use crate::parser::ast::classes::ClassStatement;
+use crate::parser::ast::identifiers::Name;
 use crate::parser::ast::identifiers::SimpleIdentifier;
 use crate::parser::ast::Statement;
 use crate::parser::ast::{Expression, NewExpression};
+use crate::parser::config::PhpVersion;
 use crate::parser::error;
 use crate::parser::error::ParseResult;
 use crate::parser::internal::attributes;
@@ -27,9 +29,12 @@ use crate::parser::internal::utils;
 use crate::parser::state::State;
 
 pub fn parse(state: &mut State) -> ParseResult<Statement> {
+    let comments = state.stream.comments();
     let attributes = state.get_attributes();
 
-    let modifiers = modifiers::class_group(modifiers::collect(state)?)?;
+    let modifiers = modifiers::collect(state)?;
+    modifiers::require_readonly(state, &modifiers, PhpVersion::Php82, "readonly classes");
+    let modifiers = modifiers::class_group(modifiers)?;
     let class = utils::skip(state, TokenKind::Class)?;
     let name = identifiers::type_identifier(state)?;
     let current = state.stream.current();
@@ -37,7 +42,7 @@ pub fn parse(state: &mut State) -> ParseResult<Statement> {
         let span = current.span;
 
         state.stream.next();
-        let parent = identifiers::full_type_name(state)?;
+        let parent = identifiers::full_type_name(state)?.to_name();
 
         Some(ClassExtends {
             extends: span,
@@ -54,8 +59,8 @@ pub fn parse(state: &mut State) -> ParseResult<Statement> {
         state.stream.next();
 
         let interfaces =
-            utils::at_least_one_comma_separated_no_trailing::<SimpleIdentifier>(state, &|state| {
-                identifiers::full_type_name(state)
+            utils::at_least_one_comma_separated_no_trailing::<Name>(state, &|state| {
+                identifiers::full_type_name(state).map(|name| name.to_name())
             })?;
 
         Some(ClassImplements {
@@ -81,6 +86,7 @@ pub fn parse(state: &mut State) -> ParseResult<Statement> {
     };
 
     Ok(Statement::Class(ClassStatement {
+        comments,
         class,
         name,
         modifiers,
@@ -114,7 +120,7 @@ pub fn parse_anonymous(state: &mut State, span: Option<Span>) -> ParseResult<Exp
         state.stream.next();
 
         let extends = current.span;
-        let parent = identifiers::full_name(state)?;
+        let parent = identifiers::full_name(state)?.to_name();
 
         Some(ClassExtends { extends, parent })
     } else {
@@ -127,8 +133,8 @@ pub fn parse_anonymous(state: &mut State, span: Option<Span>) -> ParseResult<Exp
 
         let implements = current.span;
         let interfaces =
-            utils::at_least_one_comma_separated_no_trailing::<SimpleIdentifier>(state, &|state| {
-                identifiers::full_name(state)
+            utils::at_least_one_comma_separated_no_trailing::<Name>(state, &|state| {
+                identifiers::full_name(state).map(|name| name.to_name())
             })?;
 
         Some(ClassImplements {
@@ -198,13 +204,13 @@ fn member(
                 if has_abstract {
                     Ok(ClassMember::AbstractMethod(method))
                 } else {
-                    Err(error::abstract_method_on_a_non_abstract_class(
+                    Err(Box::new(error::abstract_method_on_a_non_abstract_class(
                         state,
                         name,
                         &method.name,
                         method.modifiers.get_abstract().unwrap().span(),
                         method.semicolon,
-                    ))
+                    )))
                 }
             }
             Method::Concrete(method) => Ok(ClassMember::ConcreteMethod(method)),
@@ -212,13 +218,13 @@ fn member(
                 if has_abstract {
                     Ok(ClassMember::AbstractConstructor(ctor))
                 } else {
-                    Err(error::abstract_method_on_a_non_abstract_class(
+                    Err(Box::new(error::abstract_method_on_a_non_abstract_class(
                         state,
                         name,
                         &ctor.name,
                         ctor.modifiers.get_abstract().unwrap().span(),
                         ctor.semicolon,
-                    ))
+                    )))
                 }
             }
             Method::ConcreteConstructor(ctor) => Ok(ClassMember::ConcreteConstructor(ctor)),
@@ -226,6 +232,7 @@ fn member(
     }
 
     // e.g: public static
+    modifiers::require_readonly(state, &modifiers, PhpVersion::Php81, "readonly properties");
     let modifiers = modifiers::property_group(modifiers)?;
 
     properties::parse(state, Some(name), modifiers).map(ClassMember::Property)
@@ -269,6 +276,7 @@ fn anonymous_member(state: &mut State) -> ParseResult<AnonymousClassMember> {
     }
 
     // e.g: public static
+    modifiers::require_readonly(state, &modifiers, PhpVersion::Php81, "readonly properties");
     let modifiers = modifiers::property_group(modifiers)?;
 
     properties::parse(state, None, modifiers).map(AnonymousClassMember::Property)
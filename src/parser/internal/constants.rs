@@ -3,8 +3,10 @@ use crate::parser::ast::constant::ClassishConstant;
 use crate::parser::ast::constant::ConstantEntry;
 use crate::parser::ast::constant::ConstantStatement;
 use crate::parser::ast::modifiers::ConstantModifierGroup;
+use crate::parser::config::PhpVersion;
 use crate::parser::error::ParseResult;
 use crate::parser::expressions;
+use crate::parser::internal::data_type;
 use crate::parser::internal::identifiers;
 use crate::parser::internal::utils;
 use crate::parser::state::State;
@@ -33,13 +35,13 @@ pub fn parse(state: &mut State) -> ParseResult<ConstantStatement> {
         }
     }
 
-    let end = utils::skip_semicolon(state)?;
+    let ending = utils::skip_ending(state)?;
 
     Ok(ConstantStatement {
         comments,
         r#const: start,
         entries,
-        semicolon: end,
+        ending,
     })
 }
 
@@ -52,6 +54,26 @@ pub fn classish(
     let comments = state.stream.comments();
     let start = utils::skip(state, TokenKind::Const)?;
 
+    // the constant is typed if its name is not followed by `=`, e.g. `const int FOO = 1;`.
+    //
+    // `static` is never a valid constant type, so it is left to fail as the constant name.
+    let r#type = if state.stream.peek().kind == TokenKind::Equals
+        || state.stream.current().kind == TokenKind::Static
+    {
+        None
+    } else {
+        let r#type = data_type::data_type(state)?;
+
+        state.require(
+            PhpVersion::Php83,
+            "typed class constants",
+            r#type.first_span(),
+            r#type.to_string().len(),
+        );
+
+        Some(r#type)
+    };
+
     let mut entries = vec![];
 
     loop {
@@ -79,6 +101,7 @@ pub fn classish(
         attributes,
         modifiers,
         r#const: start,
+        r#type,
         entries,
         semicolon: end,
     })
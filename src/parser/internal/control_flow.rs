@@ -1,5 +1,7 @@
 use crate::expected_token_err;
 
+use crate::lexer::token::OpenTagKind;
+use crate::lexer::token::Span;
 use crate::lexer::token::TokenKind;
 use crate::parser;
 use crate::parser::ast::control_flow::IfStatement;
@@ -15,15 +17,19 @@ use crate::parser::ast::MatchArm;
 use crate::parser::ast::Statement;
 use crate::parser::ast::SwitchStatement;
 use crate::parser::ast::{Block, MatchExpression};
+use crate::parser::config::PhpVersion;
 use crate::parser::error;
 use crate::parser::error::ParseResult;
 use crate::parser::expressions;
 use crate::parser::internal::blocks;
 use crate::parser::internal::utils;
+use crate::parser::state::Scope;
 use crate::parser::state::State;
+use crate::scoped;
 
 pub fn match_expression(state: &mut State) -> ParseResult<Expression> {
     let keyword = utils::skip(state, TokenKind::Match)?;
+    state.require(PhpVersion::Php80, "match expressions", keyword, 5);
 
     let (left_parenthesis, condition, right_parenthesis) =
         utils::parenthesized(state, &|state: &mut State| {
@@ -38,10 +44,10 @@ pub fn match_expression(state: &mut State) -> ParseResult<Expression> {
         let current = state.stream.current();
         if current.kind == TokenKind::Default {
             if let Some(default_arm) = default {
-                return Err(error::match_expression_has_multiple_default_arms(
+                return Err(Box::new(error::match_expression_has_multiple_default_arms(
                     default_arm.keyword,
                     current.span,
-                ));
+                )));
             }
 
             state.stream.next();
@@ -61,6 +67,12 @@ pub fn match_expression(state: &mut State) -> ParseResult<Expression> {
                 body,
             }));
         } else {
+            // an arm must have at least one condition, and stray commas
+            // are only allowed after a condition, or after an arm.
+            if matches!(current.kind, TokenKind::Comma | TokenKind::DoubleArrow) {
+                return expected_token_err!(["a match arm"], state);
+            }
+
             let mut conditions = Vec::new();
             while state.stream.current().kind != TokenKind::DoubleArrow {
                 conditions.push(expressions::create(state)?);
@@ -72,10 +84,6 @@ pub fn match_expression(state: &mut State) -> ParseResult<Expression> {
                 }
             }
 
-            if conditions.is_empty() {
-                break;
-            }
-
             let arrow = utils::skip_double_arrow(state)?;
 
             let body = expressions::create(state)?;
@@ -122,6 +130,31 @@ pub fn switch_statement(state: &mut State) -> ParseResult<Statement> {
         TokenKind::RightBrace
     };
 
+    // a closing tag is allowed before the first case, as long as
+    // only whitespace separates it from the next opening tag, e.g:
+    //
+    // <?php switch ($a): ?>
+    // <?php case 1: ?>
+    if state.stream.current().kind == TokenKind::CloseTag {
+        state.stream.next();
+
+        let current = state.stream.current();
+        if current.kind == TokenKind::InlineHtml
+            && current.value.iter().all(|c| c.is_ascii_whitespace())
+        {
+            state.stream.next();
+        }
+
+        utils::skip_any_of(
+            state,
+            &[
+                TokenKind::OpenTag(OpenTagKind::Full),
+                TokenKind::OpenTag(OpenTagKind::Short),
+            ],
+        )?;
+    }
+
+    let mut default: Option<Span> = None;
     let mut cases = Vec::new();
     while state.stream.current().kind != end_token {
         match state.stream.current().kind {
@@ -132,15 +165,7 @@ pub fn switch_statement(state: &mut State) -> ParseResult<Statement> {
 
                 utils::skip_any_of(state, &[TokenKind::Colon, TokenKind::SemiColon])?;
 
-                let mut body = Block::new();
-
-                while state.stream.current().kind != TokenKind::Case
-                    && state.stream.current().kind != TokenKind::Default
-                    && state.stream.current().kind != TokenKind::RightBrace
-                    && state.stream.current().kind != end_token
-                {
-                    body.push(parser::statement(state)?);
-                }
+                let body = case_body(state, &end_token)?;
 
                 cases.push(Case {
                     condition: Some(condition),
@@ -148,18 +173,19 @@ pub fn switch_statement(state: &mut State) -> ParseResult<Statement> {
                 });
             }
             TokenKind::Default => {
+                let current = state.stream.current();
+                if let Some(first) = default {
+                    return Err(Box::new(
+                        error::switch_statement_has_multiple_default_cases(first, current.span),
+                    ));
+                }
+
+                default = Some(current.span);
                 state.stream.next();
 
                 utils::skip_any_of(state, &[TokenKind::Colon, TokenKind::SemiColon])?;
 
-                let mut body = Block::new();
-
-                while state.stream.current().kind != TokenKind::Case
-                    && state.stream.current().kind != TokenKind::Default
-                    && state.stream.current().kind != end_token
-                {
-                    body.push(parser::statement(state)?);
-                }
+                let body = case_body(state, &end_token)?;
 
                 cases.push(Case {
                     condition: None,
@@ -167,7 +193,7 @@ pub fn switch_statement(state: &mut State) -> ParseResult<Statement> {
                 });
             }
             _ => {
-                return expected_token_err!(["`case`", "`default`"], state);
+                return expected_token_err!(["case", "default"], state);
             }
         }
     }
@@ -188,6 +214,27 @@ pub fn switch_statement(state: &mut State) -> ParseResult<Statement> {
     }))
 }
 
+/// The statements of a `case` or `default` run until the next case, or until the
+/// end of the switch, which is `}` or `endswitch` depending on the syntax used.
+fn case_body(state: &mut State, end_token: &TokenKind) -> ParseResult<Block> {
+    let mut body = Block::new();
+
+    while state.stream.current().kind != TokenKind::Case
+        && state.stream.current().kind != TokenKind::Default
+        && &state.stream.current().kind != end_token
+    {
+        blocks::comments(state, &mut body);
+
+        body.push(scoped!(state, Scope::Switch, {
+            parser::recoverable(state, parser::statement)?
+        }));
+    }
+
+    blocks::trailing_comments(state, &mut body);
+
+    Ok(body)
+}
+
 pub fn if_statement(state: &mut State) -> ParseResult<Statement> {
     let r#if = utils::skip(state, TokenKind::If)?;
 
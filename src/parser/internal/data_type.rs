@@ -3,6 +3,7 @@ use crate::lexer::token::TokenKind;
 use crate::parser::ast::data_type::Type;
 use crate::parser::error;
 use crate::parser::error::ParseResult;
+use crate::parser::internal::identifiers;
 use crate::parser::internal::utils;
 use crate::parser::state::State;
 use crate::peek_token;
@@ -70,10 +71,14 @@ pub fn optional_data_type(state: &mut State) -> ParseResult<Option<Type>> {
 
 fn dnf(state: &mut State) -> ParseResult<Type> {
     // (A|B|..)&C.. or (A&B&..)|C..
+    let left_parenthesis = state.stream.current().span;
     state.stream.next();
     let ty = simple_data_type(state)?;
     peek_token!([
         TokenKind::Pipe => {
+            // (A|B|..)&C.. is not a valid disjunctive normal form type.
+            state.record(error::union_type_grouped_in_intersection(left_parenthesis));
+
             let union = union(state, ty, true)?;
 
             utils::skip_right_parenthesis(state)?;
@@ -178,6 +183,7 @@ fn optional_simple_data_type(state: &mut State) -> ParseResult<Option<Type>> {
         TokenKind::QualifiedIdentifier | TokenKind::FullyQualifiedIdentifier => {
             let name = current.value.clone();
             let span = current.span;
+            identifiers::require_unreserved_segments(state, current);
             state.stream.next();
 
             Ok(Some(Type::Named(span, name)))
@@ -188,7 +194,7 @@ fn optional_simple_data_type(state: &mut State) -> ParseResult<Option<Type>> {
 
 fn simple_data_type(state: &mut State) -> ParseResult<Type> {
     // TODO(azjezz): add a better error message here.
-    optional_simple_data_type(state)?.ok_or_else(|| expected_token!(["a type"], state))
+    optional_simple_data_type(state)?.ok_or_else(|| Box::new(expected_token!(["a type"], state)))
 }
 
 fn nullable(state: &mut State) -> ParseResult<Type> {
@@ -202,7 +208,24 @@ fn nullable(state: &mut State) -> ParseResult<Type> {
         state.record(error::standalone_type_used_as_nullable(&ty, current.span));
     }
 
-    Ok(Type::Nullable(current.span, Box::new(ty)))
+    let ty = Type::Nullable(current.span, Box::new(ty));
+
+    // ?A|B and ?A&B are not allowed, parse the rest of the type
+    // so that the error points at the nullable type.
+    if state.stream.current().kind == TokenKind::Pipe {
+        return union(state, ty, false);
+    }
+
+    if state.stream.current().kind == TokenKind::Ampersand
+        && !matches!(
+            state.stream.peek().kind,
+            TokenKind::Variable | TokenKind::Ellipsis | TokenKind::Ampersand
+        )
+    {
+        return intersection(state, ty, false);
+    }
+
+    Ok(ty)
 }
 
 fn union(state: &mut State, other: Type, within_dnf: bool) -> ParseResult<Type> {
@@ -251,7 +274,20 @@ fn union(state: &mut State, other: Type, within_dnf: bool) -> ParseResult<Type>
                 state.record(error::standalone_type_used_in_union(&ty, last_pipe));
             }
 
-            ty
+            let current = state.stream.current();
+            if current.kind == TokenKind::Ampersand
+                && !matches!(
+                    state.stream.peek().kind,
+                    TokenKind::Variable | TokenKind::Ellipsis | TokenKind::Ampersand
+                )
+            {
+                // A|B&C
+                state.record(error::union_and_intersection_types_mixed(current.span));
+
+                intersection(state, ty, true)?
+            } else {
+                ty
+            }
         };
 
         types.push(ty);
@@ -296,6 +332,9 @@ fn intersection(state: &mut State, other: Type, within_dnf: bool) -> ParseResult
                 //        v-- error
                 // F|(A&B&(D|S))
                 state.record(error::nested_disjunctive_normal_form_types(current.span));
+            } else {
+                // F&(A|B) is not a valid disjunctive normal form type.
+                state.record(error::union_type_grouped_in_intersection(current.span));
             }
 
             state.stream.next();
@@ -332,5 +371,13 @@ fn intersection(state: &mut State, other: Type, within_dnf: bool) -> ParseResult
         }
     }
 
+    let current = state.stream.current();
+    if !within_dnf && current.kind == TokenKind::Pipe {
+        // A&B|C
+        state.record(error::union_and_intersection_types_mixed(current.span));
+
+        return union(state, Type::Intersection(types), true);
+    }
+
     Ok(Type::Intersection(types))
 }
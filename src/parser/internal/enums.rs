@@ -12,6 +12,7 @@ use crate::parser::ast::enums::UnitEnumStatement;
 use crate::parser::ast::functions::ConcreteMethod;
 use crate::parser::ast::identifiers::SimpleIdentifier;
 use crate::parser::ast::Statement;
+use crate::parser::config::PhpVersion;
 use crate::parser::error;
 use crate::parser::error::ParseResult;
 use crate::parser::expressions;
@@ -27,7 +28,9 @@ use crate::parser::state::State;
 use super::traits;
 
 pub fn parse(state: &mut State) -> ParseResult<Statement> {
+    let comments = state.stream.comments();
     let span = utils::skip(state, TokenKind::Enum)?;
+    state.require(PhpVersion::Php81, "enums", span, 4);
 
     let name = identifiers::type_identifier(state)?;
 
@@ -35,7 +38,7 @@ pub fn parse(state: &mut State) -> ParseResult<Statement> {
         let span = utils::skip_colon(state)?;
 
         let identifier = identifiers::identifier_of(state, &["string", "int"])?;
-        Some(match &identifier.value[..] {
+        Some(match &identifier.value.to_ascii_lowercase()[..] {
             b"string" => BackedEnumType::String(span, identifier.span),
             b"int" => BackedEnumType::Int(span, identifier.span),
             _ => unreachable!(),
@@ -49,7 +52,7 @@ pub fn parse(state: &mut State) -> ParseResult<Statement> {
         state.stream.next();
 
         while state.stream.current().kind != TokenKind::LeftBrace {
-            implements.push(identifiers::full_type_name(state)?);
+            implements.push(identifiers::full_type_name(state)?.to_name());
 
             if state.stream.current().kind == TokenKind::Comma {
                 state.stream.next();
@@ -77,6 +80,7 @@ pub fn parse(state: &mut State) -> ParseResult<Statement> {
         };
 
         Ok(Statement::BackedEnum(BackedEnumStatement {
+            comments,
             r#enum: span,
             name,
             backed_type,
@@ -100,6 +104,7 @@ pub fn parse(state: &mut State) -> ParseResult<Statement> {
         };
 
         Ok(Statement::UnitEnum(UnitEnumStatement {
+            comments,
             r#enum: span,
             name,
             attributes,
@@ -156,6 +161,15 @@ fn unit_member(
 
     let modifiers = modifiers::collect(state)?;
 
+    if state.stream.current().kind == TokenKind::Case {
+        if let Some((span, modifier)) = modifiers.first() {
+            return Err(Box::new(error::modifier_cannot_be_used_for_enum_case(
+                modifier.to_string(),
+                *span,
+            )));
+        }
+    }
+
     if state.stream.current().kind == TokenKind::Const {
         return constants::classish(state, modifiers::constant_group(modifiers)?)
             .map(UnitEnumMember::Constant)
@@ -217,6 +231,15 @@ fn backed_member(
 
     let modifiers = modifiers::collect(state)?;
 
+    if state.stream.current().kind == TokenKind::Case {
+        if let Some((span, modifier)) = modifiers.first() {
+            return Err(Box::new(error::modifier_cannot_be_used_for_enum_case(
+                modifier.to_string(),
+                *span,
+            )));
+        }
+    }
+
     if state.stream.current().kind == TokenKind::Const {
         return constants::classish(state, modifiers::constant_group(modifiers)?)
             .map(BackedEnumMember::Constant)
@@ -1,3 +1,4 @@
+use crate::lexer::token::Span;
 use crate::lexer::token::TokenKind;
 use crate::parser::ast::functions::AbstractConstructor;
 use crate::parser::ast::functions::AbstractMethod;
@@ -15,6 +16,8 @@ use crate::parser::ast::identifiers::SimpleIdentifier;
 use crate::parser::ast::modifiers::MethodModifierGroup;
 use crate::parser::ast::Expression;
 use crate::parser::ast::Statement;
+use crate::parser::config::PhpVersion;
+use crate::parser::error;
 use crate::parser::error::ParseResult;
 use crate::parser::expressions;
 use crate::parser::internal::blocks;
@@ -23,7 +26,9 @@ use crate::parser::internal::identifiers;
 use crate::parser::internal::parameters;
 use crate::parser::internal::utils;
 use crate::parser::internal::variables;
+use crate::parser::state::Scope;
 use crate::parser::state::State;
+use crate::scoped;
 
 pub enum MethodType {
     Abstract,
@@ -67,33 +72,46 @@ pub fn anonymous_function(state: &mut State) -> ParseResult<Expression> {
     let uses = if current.kind == TokenKind::Use {
         state.stream.next();
 
+        let comments = state.stream.comments();
+        let left_parenthesis = utils::skip_left_parenthesis(state)?;
+        let variables = utils::comma_separated::<ClosureUseVariable>(
+            state,
+            &|state| {
+                let use_comments = state.stream.comments();
+                let current = state.stream.current();
+                let use_ampersand = if current.kind == TokenKind::Ampersand {
+                    state.stream.next();
+
+                    Some(current.span)
+                } else {
+                    None
+                };
+
+                let var = variables::simple_variable(state)?;
+
+                Ok(ClosureUseVariable {
+                    comments: use_comments,
+                    variable: var,
+                    ampersand: use_ampersand,
+                })
+            },
+            TokenKind::RightParen,
+        )?;
+
+        if let Some(comma) = variables.trailing_comma() {
+            state.require(
+                PhpVersion::Php80,
+                "trailing commas in closure use lists",
+                comma,
+                1,
+            );
+        }
+
         Some(ClosureUse {
-            comments: state.stream.comments(),
+            comments,
             r#use: current.span,
-            left_parenthesis: utils::skip_left_parenthesis(state)?,
-            variables: utils::comma_separated::<ClosureUseVariable>(
-                state,
-                &|state| {
-                    let use_comments = state.stream.comments();
-                    let current = state.stream.current();
-                    let use_ampersand = if current.kind == TokenKind::Ampersand {
-                        state.stream.next();
-
-                        Some(current.span)
-                    } else {
-                        None
-                    };
-
-                    let var = variables::simple_variable(state)?;
-
-                    Ok(ClosureUseVariable {
-                        comments: use_comments,
-                        variable: var,
-                        ampersand: use_ampersand,
-                    })
-                },
-                TokenKind::RightParen,
-            )?,
+            left_parenthesis,
+            variables,
             right_parenthesis: utils::skip_right_parenthesis(state)?,
         })
     } else {
@@ -112,7 +130,9 @@ pub fn anonymous_function(state: &mut State) -> ParseResult<Expression> {
     let body = FunctionBody {
         comments: state.stream.comments(),
         left_brace: utils::skip_left_brace(state)?,
-        statements: blocks::multiple_statements_until(state, &TokenKind::RightBrace)?,
+        statements: scoped!(state, Scope::AnonymousFunction, {
+            blocks::multiple_statements_until(state, &TokenKind::RightBrace)?
+        }),
         right_brace: utils::skip_right_brace(state)?,
     };
 
@@ -164,7 +184,9 @@ pub fn arrow_function(state: &mut State) -> ParseResult<Expression> {
 
     let double_arrow = utils::skip(state, TokenKind::DoubleArrow)?;
 
-    let body = Box::new(expressions::create(state)?);
+    let body = scoped!(state, Scope::AnonymousFunction, {
+        Box::new(expressions::create(state)?)
+    });
 
     Ok(Expression::ArrowFunction(ArrowFunctionExpression {
         comments,
@@ -179,6 +201,24 @@ pub fn arrow_function(state: &mut State) -> ParseResult<Expression> {
     }))
 }
 
+/// Determine whether the `function` keyword at the current position starts
+/// a function declaration, as opposed to a closure expression.
+pub fn is_function_declaration(state: &mut State) -> bool {
+    let checkpoint = state.checkpoint();
+
+    state.stream.next();
+    if state.stream.current().kind == TokenKind::Ampersand {
+        state.stream.next();
+    }
+
+    let is_declaration =
+        identifiers::is_identifier_maybe_soft_reserved(&state.stream.current().kind);
+
+    state.restore(checkpoint);
+
+    is_declaration
+}
+
 pub fn function(state: &mut State) -> ParseResult<Statement> {
     let comments = state.stream.comments();
 
@@ -212,7 +252,9 @@ pub fn function(state: &mut State) -> ParseResult<Statement> {
     let body = FunctionBody {
         comments: state.stream.comments(),
         left_brace: utils::skip_left_brace(state)?,
-        statements: blocks::multiple_statements_until(state, &TokenKind::RightBrace)?,
+        statements: scoped!(state, Scope::Function, {
+            blocks::multiple_statements_until(state, &TokenKind::RightBrace)?
+        }),
         right_brace: utils::skip_right_brace(state)?,
     };
 
@@ -253,14 +295,31 @@ pub fn method(
         MethodType::Concrete => true,
         MethodType::DependingOnModifiers => !modifiers.has_abstract(),
     };
+    let scope = if modifiers.has_static() {
+        Scope::StaticMethod
+    } else {
+        Scope::Method
+    };
 
     if name.to_string().to_lowercase() == "__construct" {
         return if has_body {
             let parameters = parameters::constructor_parameter_list(state, class)?;
+            let current = state.stream.current();
+            if current.kind == TokenKind::SemiColon {
+                return Err(Box::new(error::missing_method_body(
+                    state,
+                    class,
+                    &name,
+                    current.span,
+                )));
+            }
+
             let body = MethodBody {
                 comments: state.stream.comments(),
                 left_brace: utils::skip_left_brace(state)?,
-                statements: blocks::multiple_statements_until(state, &TokenKind::RightBrace)?,
+                statements: scoped!(state, Scope::Method, {
+                    blocks::multiple_statements_until(state, &TokenKind::RightBrace)?
+                }),
                 right_brace: utils::skip_right_brace(state)?,
             };
 
@@ -276,7 +335,7 @@ pub fn method(
             }))
         } else {
             let parameters = parameters::function_parameter_list(state)?;
-            let semicolon = utils::skip_semicolon(state)?;
+            let semicolon = abstract_method_ending(state, &r#type, class, &name)?;
 
             Ok(Method::AbstractConstructor(AbstractConstructor {
                 comments,
@@ -301,6 +360,16 @@ pub fn method(
         None
     };
 
+    let current = state.stream.current();
+    if has_body && current.kind == TokenKind::SemiColon {
+        return Err(Box::new(error::missing_method_body(
+            state,
+            class,
+            &name,
+            current.span,
+        )));
+    }
+
     if has_body {
         Ok(Method::Concrete(ConcreteMethod {
             comments,
@@ -314,11 +383,15 @@ pub fn method(
             body: MethodBody {
                 comments: state.stream.comments(),
                 left_brace: utils::skip_left_brace(state)?,
-                statements: blocks::multiple_statements_until(state, &TokenKind::RightBrace)?,
+                statements: scoped!(state, scope, {
+                    blocks::multiple_statements_until(state, &TokenKind::RightBrace)?
+                }),
                 right_brace: utils::skip_right_brace(state)?,
             },
         }))
     } else {
+        let semicolon = abstract_method_ending(state, &r#type, class, &name)?;
+
         Ok(Method::Abstract(AbstractMethod {
             comments,
             attributes,
@@ -328,7 +401,36 @@ pub fn method(
             name,
             parameters,
             return_type,
-            semicolon: utils::skip_semicolon(state)?,
+            semicolon,
         }))
     }
 }
+
+/// Skip the `;` ending an abstract method.
+///
+/// If the method has a body instead, an error is recorded and the body is parsed
+/// so that parsing can continue, with its closing brace taking the place of the `;`.
+fn abstract_method_ending(
+    state: &mut State,
+    r#type: &MethodType,
+    class: Option<&SimpleIdentifier>,
+    name: &SimpleIdentifier,
+) -> ParseResult<Span> {
+    let current = state.stream.current();
+    if current.kind != TokenKind::LeftBrace {
+        return utils::skip_semicolon(state);
+    }
+
+    let error = match r#type {
+        MethodType::Abstract => error::interface_method_with_body(state, class, name, current.span),
+        _ => error::abstract_method_with_body(state, class, name, current.span),
+    };
+    state.record(error);
+
+    utils::skip_left_brace(state)?;
+    scoped!(state, Scope::Method, {
+        blocks::multiple_statements_until(state, &TokenKind::RightBrace)?
+    });
+
+    utils::skip_right_brace(state)
+}
@@ -23,12 +23,12 @@ pub fn goto_statement(state: &mut State) -> ParseResult<Statement> {
     let comments = state.stream.comments();
     let keyword = utils::skip(state, TokenKind::Goto)?;
     let label = identifiers::label_identifier(state)?;
-    let semicolon = utils::skip_semicolon(state)?;
+    let ending = utils::skip_ending(state)?;
 
     Ok(Statement::Goto(GotoStatement {
         comments,
         keyword,
         label,
-        semicolon,
+        ending,
     }))
 }
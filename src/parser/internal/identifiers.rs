@@ -1,24 +1,29 @@
+use crate::lexer::identifier_to_keyword;
+use crate::lexer::token::Span;
+use crate::lexer::token::Token;
 use crate::lexer::token::TokenKind;
 use crate::parser::ast::identifiers::SimpleIdentifier;
+use crate::parser::config::PhpVersion;
 use crate::parser::error;
 use crate::parser::error::ParseResult;
 use crate::parser::state::State;
 
 use crate::peek_token;
 
+/// Expect an unqualified identifier matching one of the given kinds, ignoring case.
 pub fn identifier_of(state: &mut State, kinds: &[&str]) -> ParseResult<SimpleIdentifier> {
     let ident = identifier(state)?;
 
     let name = ident.value.to_string();
 
-    if kinds.contains(&name.as_str()) {
+    if kinds.iter().any(|kind| kind.eq_ignore_ascii_case(&name)) {
         Ok(ident)
     } else {
-        Err(error::unexpected_identifier(
+        Err(Box::new(error::unexpected_identifier(
             kinds.iter().map(|s| s.to_string()).collect(),
             name,
             ident.span,
-        ))
+        )))
     }
 }
 
@@ -70,10 +75,10 @@ pub fn type_identifier(state: &mut State) -> ParseResult<SimpleIdentifier> {
 
             Ok(SimpleIdentifier { span, value: name })
         }
-        _ => Err(error::unexpected_token(
+        _ => Err(Box::new(error::unexpected_token(
             vec!["an identifier".to_owned()],
             current,
-        )),
+        ))),
     }
 }
 
@@ -126,10 +131,10 @@ pub fn label_identifier(state: &mut State) -> ParseResult<SimpleIdentifier> {
 
             Ok(SimpleIdentifier { span, value: name })
         }
-        _ => Err(error::unexpected_token(
+        _ => Err(Box::new(error::unexpected_token(
             vec!["an identifier".to_owned()],
             current,
-        )),
+        ))),
     }
 }
 
@@ -168,10 +173,10 @@ pub fn constant_identifier(state: &mut State) -> ParseResult<SimpleIdentifier> {
 
             Ok(SimpleIdentifier { span, value: name })
         }
-        _ => Err(error::unexpected_token(
+        _ => Err(Box::new(error::unexpected_token(
             vec!["an identifier".to_owned()],
             current,
-        )),
+        ))),
     }
 }
 
@@ -188,10 +193,10 @@ pub fn identifier(state: &mut State) -> ParseResult<SimpleIdentifier> {
             value: current.value.clone(),
         })
     } else {
-        Err(error::unexpected_token(
+        Err(Box::new(error::unexpected_token(
             vec!["an identifier".to_owned()],
             current,
-        ))
+        )))
     }
 }
 
@@ -204,6 +209,7 @@ pub fn name(state: &mut State) -> ParseResult<SimpleIdentifier> {
     ], state, "an identifier");
 
     let span = state.stream.current().span;
+    require_unreserved_segments(state, state.stream.current());
     state.stream.next();
 
     Ok(SimpleIdentifier { span, value: name })
@@ -215,6 +221,7 @@ pub fn optional_name(state: &mut State) -> Option<SimpleIdentifier> {
 
     match &current.kind {
         TokenKind::Identifier | TokenKind::QualifiedIdentifier => {
+            require_unreserved_segments(state, current);
             state.stream.next();
 
             Some(SimpleIdentifier {
@@ -243,6 +250,7 @@ pub fn full_name(state: &mut State) -> ParseResult<SimpleIdentifier> {
         | TokenKind::FullyQualifiedIdentifier => {
             let span = current.span;
 
+            require_unreserved_segments(state, current);
             state.stream.next();
 
             Ok(SimpleIdentifier {
@@ -250,10 +258,10 @@ pub fn full_name(state: &mut State) -> ParseResult<SimpleIdentifier> {
                 value: current.value.clone(),
             })
         }
-        _ => Err(error::unexpected_token(
+        _ => Err(Box::new(error::unexpected_token(
             vec!["an identifier".to_owned()],
             current,
-        )),
+        ))),
     }
 }
 
@@ -266,6 +274,7 @@ pub fn full_type_name(state: &mut State) -> ParseResult<SimpleIdentifier> {
         | TokenKind::FullyQualifiedIdentifier => {
             let span = current.span;
 
+            require_unreserved_segments(state, current);
             state.stream.next();
 
             Ok(SimpleIdentifier {
@@ -307,10 +316,10 @@ pub fn full_type_name(state: &mut State) -> ParseResult<SimpleIdentifier> {
 
             Ok(SimpleIdentifier { span, value: name })
         }
-        _ => Err(error::unexpected_token(
+        _ => Err(Box::new(error::unexpected_token(
             vec!["an identifier".to_owned()],
             current,
-        )),
+        ))),
     }
 }
 
@@ -323,6 +332,7 @@ pub fn full_type_name_including_self(state: &mut State) -> ParseResult<SimpleIde
         | TokenKind::FullyQualifiedIdentifier => {
             let span = current.span;
 
+            require_unreserved_segments(state, current);
             state.stream.next();
 
             Ok(SimpleIdentifier {
@@ -355,10 +365,10 @@ pub fn full_type_name_including_self(state: &mut State) -> ParseResult<SimpleIde
 
             Ok(SimpleIdentifier { span, value: name })
         }
-        _ => Err(error::unexpected_token(
+        _ => Err(Box::new(error::unexpected_token(
             vec!["an identifier".to_owned()],
             current,
-        )),
+        ))),
     }
 }
 
@@ -406,6 +416,54 @@ pub fn is_identifier_maybe_reserved(kind: &TokenKind) -> bool {
     is_reserved_identifier(kind)
 }
 
+/// Reserved keywords can be used as segments of a qualified name, such as `Foo\List\Bar`,
+/// from PHP 8.0 onwards, as qualified names are then lexed as a single token.
+pub fn require_unreserved_segments(state: &mut State, token: &Token) {
+    if !matches!(
+        token.kind,
+        TokenKind::QualifiedIdentifier | TokenKind::FullyQualifiedIdentifier
+    ) {
+        return;
+    }
+
+    let mut offset = 0;
+    for segment in token.value.split(|byte| *byte == b'\\') {
+        let reserved = match identifier_to_keyword(segment) {
+            // these were not yet keywords in PHP 7.4, so could always be used.
+            Some(
+                TokenKind::Self_
+                | TokenKind::Parent
+                | TokenKind::True
+                | TokenKind::False
+                | TokenKind::Null
+                | TokenKind::Enum
+                | TokenKind::From
+                | TokenKind::Readonly
+                | TokenKind::Match,
+            ) => false,
+            Some(kind) => is_reserved_identifier(&kind),
+            None => false,
+        };
+
+        if reserved {
+            let span = Span {
+                column: token.span.column + offset,
+                position: token.span.position + offset,
+                ..token.span
+            };
+
+            state.require(
+                PhpVersion::Php80,
+                "reserved keywords in namespaced names",
+                span,
+                segment.len(),
+            );
+        }
+
+        offset += segment.len() + 1;
+    }
+}
+
 pub fn is_soft_reserved_identifier(kind: &TokenKind) -> bool {
     matches!(kind, |TokenKind::Parent| TokenKind::Self_
         | TokenKind::True
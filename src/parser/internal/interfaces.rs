@@ -1,4 +1,5 @@
 use crate::lexer::token::TokenKind;
+use crate::parser::ast::identifiers::Name;
 use crate::parser::ast::identifiers::SimpleIdentifier;
 use crate::parser::ast::interfaces::InterfaceBody;
 use crate::parser::ast::interfaces::InterfaceExtends;
@@ -17,6 +18,7 @@ use crate::parser::internal::utils;
 use crate::parser::state::State;
 
 pub fn parse(state: &mut State) -> ParseResult<Statement> {
+    let comments = state.stream.comments();
     let span = utils::skip(state, TokenKind::Interface)?;
 
     let name = identifiers::type_identifier(state)?;
@@ -27,10 +29,9 @@ pub fn parse(state: &mut State) -> ParseResult<Statement> {
 
         state.stream.next();
 
-        let parents =
-            utils::at_least_one_comma_separated_no_trailing::<SimpleIdentifier>(state, &|state| {
-                identifiers::full_type_name(state)
-            })?;
+        let parents = utils::at_least_one_comma_separated_no_trailing::<Name>(state, &|state| {
+            identifiers::full_type_name(state).map(|name| name.to_name())
+        })?;
 
         Some(InterfaceExtends {
             extends: span,
@@ -56,6 +57,7 @@ pub fn parse(state: &mut State) -> ParseResult<Statement> {
     };
 
     Ok(Statement::Interface(InterfaceStatement {
+        comments,
         interface: span,
         name,
         attributes,
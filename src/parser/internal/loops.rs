@@ -1,3 +1,4 @@
+use crate::lexer::token::Span;
 use crate::lexer::token::Token;
 use crate::lexer::token::TokenKind;
 use crate::parser;
@@ -15,11 +16,14 @@ use crate::parser::ast::loops::Level;
 use crate::parser::ast::loops::WhileStatement;
 use crate::parser::ast::loops::WhileStatementBody;
 use crate::parser::ast::Statement;
+use crate::parser::error;
 use crate::parser::error::ParseResult;
 use crate::parser::expressions;
 use crate::parser::internal::blocks;
 use crate::parser::internal::utils;
+use crate::parser::state::Scope;
 use crate::parser::state::State;
+use crate::scoped;
 
 pub fn foreach_statement(state: &mut State) -> ParseResult<Statement> {
     let foreach = utils::skip(state, TokenKind::Foreach)?;
@@ -75,18 +79,20 @@ pub fn foreach_statement(state: &mut State) -> ParseResult<Statement> {
             }
         })?;
 
-    let body = if state.stream.current().kind == TokenKind::Colon {
-        ForeachStatementBody::Block {
-            colon: utils::skip_colon(state)?,
-            statements: blocks::multiple_statements_until(state, &TokenKind::EndForeach)?,
-            endforeach: utils::skip(state, TokenKind::EndForeach)?,
-            ending: utils::skip_ending(state)?,
-        }
-    } else {
-        ForeachStatementBody::Statement {
-            statement: parser::statement(state).map(Box::new)?,
+    let body = scoped!(state, Scope::Loop, {
+        if state.stream.current().kind == TokenKind::Colon {
+            ForeachStatementBody::Block {
+                colon: utils::skip_colon(state)?,
+                statements: blocks::multiple_statements_until(state, &TokenKind::EndForeach)?,
+                endforeach: utils::skip(state, TokenKind::EndForeach)?,
+                ending: utils::skip_ending(state)?,
+            }
+        } else {
+            ForeachStatementBody::Statement {
+                statement: parser::statement(state).map(Box::new)?,
+            }
         }
-    };
+    });
 
     Ok(Statement::Foreach(ForeachStatement {
         foreach,
@@ -127,18 +133,20 @@ pub fn for_statement(state: &mut State) -> ParseResult<Statement> {
         })
     })?;
 
-    let body = if state.stream.current().kind == TokenKind::Colon {
-        ForStatementBody::Block {
-            colon: utils::skip_colon(state)?,
-            statements: blocks::multiple_statements_until(state, &TokenKind::EndFor)?,
-            endfor: utils::skip(state, TokenKind::EndFor)?,
-            ending: utils::skip_ending(state)?,
-        }
-    } else {
-        ForStatementBody::Statement {
-            statement: parser::statement(state).map(Box::new)?,
+    let body = scoped!(state, Scope::Loop, {
+        if state.stream.current().kind == TokenKind::Colon {
+            ForStatementBody::Block {
+                colon: utils::skip_colon(state)?,
+                statements: blocks::multiple_statements_until(state, &TokenKind::EndFor)?,
+                endfor: utils::skip(state, TokenKind::EndFor)?,
+                ending: utils::skip_ending(state)?,
+            }
+        } else {
+            ForStatementBody::Statement {
+                statement: parser::statement(state).map(Box::new)?,
+            }
         }
-    };
+    });
 
     Ok(Statement::For(ForStatement {
         r#for,
@@ -152,14 +160,14 @@ pub fn for_statement(state: &mut State) -> ParseResult<Statement> {
 pub fn do_while_statement(state: &mut State) -> ParseResult<Statement> {
     let r#do = utils::skip(state, TokenKind::Do)?;
 
-    let body = parser::statement(state).map(Box::new)?;
+    let body = scoped!(state, Scope::Loop, {
+        parser::statement(state).map(Box::new)?
+    });
 
     let r#while = utils::skip(state, TokenKind::While)?;
 
-    let (semicolon, (left_parenthesis, condition, right_parenthesis)) =
-        utils::semicolon_terminated(state, &|state| {
-            utils::parenthesized(state, &expressions::create)
-        })?;
+    let (left_parenthesis, condition, right_parenthesis) =
+        utils::parenthesized(state, &expressions::create)?;
 
     Ok(Statement::DoWhile(DoWhileStatement {
         r#do,
@@ -168,7 +176,7 @@ pub fn do_while_statement(state: &mut State) -> ParseResult<Statement> {
         left_parenthesis,
         condition,
         right_parenthesis,
-        semicolon,
+        ending: utils::skip_ending(state)?,
     }))
 }
 
@@ -178,18 +186,20 @@ pub fn while_statement(state: &mut State) -> ParseResult<Statement> {
     let (left_parenthesis, condition, right_parenthesis) =
         utils::parenthesized(state, &expressions::create)?;
 
-    let body = if state.stream.current().kind == TokenKind::Colon {
-        WhileStatementBody::Block {
-            colon: utils::skip_colon(state)?,
-            statements: blocks::multiple_statements_until(state, &TokenKind::EndWhile)?,
-            endwhile: utils::skip(state, TokenKind::EndWhile)?,
-            ending: utils::skip_ending(state)?,
-        }
-    } else {
-        WhileStatementBody::Statement {
-            statement: parser::statement(state).map(Box::new)?,
+    let body = scoped!(state, Scope::Loop, {
+        if state.stream.current().kind == TokenKind::Colon {
+            WhileStatementBody::Block {
+                colon: utils::skip_colon(state)?,
+                statements: blocks::multiple_statements_until(state, &TokenKind::EndWhile)?,
+                endwhile: utils::skip(state, TokenKind::EndWhile)?,
+                ending: utils::skip_ending(state)?,
+            }
+        } else {
+            WhileStatementBody::Statement {
+                statement: parser::statement(state).map(Box::new)?,
+            }
         }
-    };
+    });
 
     Ok(Statement::While(WhileStatement {
         r#while,
@@ -201,54 +211,112 @@ pub fn while_statement(state: &mut State) -> ParseResult<Statement> {
 }
 
 pub fn continue_statement(state: &mut State) -> ParseResult<Statement> {
+    let r#continue = utils::skip(state, TokenKind::Continue)?;
+    let level = maybe_loop_level(state, &TokenKind::Continue)?;
+
+    validate_loop_context(state, &TokenKind::Continue, r#continue, level.as_ref());
+
     Ok(Statement::Continue(ContinueStatement {
-        r#continue: utils::skip(state, TokenKind::Continue)?,
-        level: maybe_loop_level(state)?,
+        r#continue,
+        level,
         ending: utils::skip_ending(state)?,
     }))
 }
 
 pub fn break_statement(state: &mut State) -> ParseResult<Statement> {
+    let r#break = utils::skip(state, TokenKind::Break)?;
+    let level = maybe_loop_level(state, &TokenKind::Break)?;
+
+    validate_loop_context(state, &TokenKind::Break, r#break, level.as_ref());
+
     Ok(Statement::Break(BreakStatement {
-        r#break: utils::skip(state, TokenKind::Break)?,
-        level: maybe_loop_level(state)?,
+        r#break,
+        level,
         ending: utils::skip_ending(state)?,
     }))
 }
 
-fn maybe_loop_level(state: &mut State) -> ParseResult<Option<Level>> {
+/// Record an error if `break` or `continue` is used outside of a loop or switch
+/// statement, or if its level exceeds the number of enclosing loops and switches.
+fn validate_loop_context(
+    state: &mut State,
+    keyword: &TokenKind,
+    span: Span,
+    level: Option<&Level>,
+) {
+    let depth = state.loop_depth();
+    if depth == 0 {
+        state.record(error::loop_control_outside_loop(keyword, span));
+
+        return;
+    }
+
+    let mut level = match level {
+        Some(level) => level,
+        None => return,
+    };
+
+    while let Level::Parenthesized { level: inner, .. } = level {
+        level = inner;
+    }
+
+    if let Level::Literal(literal) = level {
+        if matches!(literal.to_i64(), Some(value) if value as usize > depth) {
+            state.record(error::loop_level_exceeds_depth(keyword, literal, depth));
+        }
+    }
+}
+
+fn maybe_loop_level(state: &mut State, keyword: &TokenKind) -> ParseResult<Option<Level>> {
     let current = &state.stream.current().kind;
 
     Ok(
         if current == &TokenKind::SemiColon || current == &TokenKind::CloseTag {
             None
         } else {
-            Some(loop_level(state)?)
+            loop_level(state, keyword)?
         },
     )
 }
 
-fn loop_level(state: &mut State) -> ParseResult<Level> {
+fn loop_level(state: &mut State, keyword: &TokenKind) -> ParseResult<Option<Level>> {
+    let current = state.stream.current();
     if let Token {
         kind: TokenKind::LiteralInteger,
         span,
         value,
-    } = state.stream.current()
+    } = current
     {
-        state.stream.next();
-
-        return Ok(Level::Literal(LiteralInteger {
+        let level = LiteralInteger {
             value: value.clone(),
             span: *span,
-        }));
+        };
+
+        // `break 0;` was allowed prior to PHP 5.4, levels must now be at least 1.
+        if !matches!(level.to_i64(), Some(value) if value > 0) {
+            state.record(error::invalid_loop_level(keyword, current));
+        }
+
+        state.stream.next();
+
+        return Ok(Some(Level::Literal(level)));
+    }
+
+    if current.kind != TokenKind::LeftParen {
+        state.record(error::invalid_loop_level(keyword, current));
+
+        // skip over the invalid level, e.g. `break $x;`, so that parsing can continue.
+        expressions::create(state)?;
+
+        return Ok(None);
     }
 
     let (left_parenthesis, level, right_parenthesis) =
-        utils::parenthesized(state, &|state| loop_level(state).map(Box::new))?;
+        utils::parenthesized(state, &|state| loop_level(state, keyword))?;
 
-    Ok(Level::Parenthesized {
+    Ok(level.map(|level| Level::Parenthesized {
         left_parenthesis,
-        level,
+        level: Box::new(level),
         right_parenthesis,
-    })
+    }))
 }
@@ -10,6 +10,7 @@ use crate::parser::ast::modifiers::PromotedPropertyModifier;
 use crate::parser::ast::modifiers::PromotedPropertyModifierGroup;
 use crate::parser::ast::modifiers::PropertyModifier;
 use crate::parser::ast::modifiers::PropertyModifierGroup;
+use crate::parser::config::PhpVersion;
 use crate::parser::error;
 use crate::parser::error::ParseResult;
 use crate::parser::state::State;
@@ -26,9 +27,11 @@ pub fn class_group(input: Vec<(Span, TokenKind)>) -> ParseResult<ClassModifierGr
             TokenKind::Final => {
                 final_span = Some(*span);
                 if let Some(abstract_span) = abstract_span {
-                    Err(error::final_and_abstract_modifiers_combined_for_class(
-                        *span,
-                        abstract_span,
+                    Err(Box::new(
+                        error::final_and_abstract_modifiers_combined_for_class(
+                            *span,
+                            abstract_span,
+                        ),
                     ))
                 } else {
                     Ok(ClassModifier::Final(*span))
@@ -37,17 +40,17 @@ pub fn class_group(input: Vec<(Span, TokenKind)>) -> ParseResult<ClassModifierGr
             TokenKind::Abstract => {
                 abstract_span = Some(*span);
                 if let Some(final_span) = final_span {
-                    Err(error::final_and_abstract_modifiers_combined_for_class(
-                        final_span, *span,
+                    Err(Box::new(
+                        error::final_and_abstract_modifiers_combined_for_class(final_span, *span),
                     ))
                 } else {
                     Ok(ClassModifier::Abstract(*span))
                 }
             }
-            _ => Err(error::modifier_cannot_be_used_for_class(
+            _ => Err(Box::new(error::modifier_cannot_be_used_for_class(
                 token.to_string(),
                 *span,
-            )),
+            ))),
         })
         .collect::<ParseResult<Vec<ClassModifier>>>()?;
 
@@ -65,12 +68,12 @@ pub fn method_group(input: Vec<(Span, TokenKind)>) -> ParseResult<MethodModifier
             TokenKind::Final => {
                 final_span = Some(*span);
                 if let Some(abstract_span) = abstract_span {
-                    Err(
+                    Err(Box::new(
                         error::final_and_abstract_modifiers_combined_for_class_member(
                             *span,
                             abstract_span,
                         ),
-                    )
+                    ))
                 } else {
                     Ok(MethodModifier::Final(*span))
                 }
@@ -78,11 +81,11 @@ pub fn method_group(input: Vec<(Span, TokenKind)>) -> ParseResult<MethodModifier
             TokenKind::Abstract => {
                 abstract_span = Some(*span);
                 if let Some(final_span) = final_span {
-                    Err(
+                    Err(Box::new(
                         error::final_and_abstract_modifiers_combined_for_class_member(
                             final_span, *span,
                         ),
-                    )
+                    ))
                 } else {
                     Ok(MethodModifier::Abstract(*span))
                 }
@@ -91,10 +94,10 @@ pub fn method_group(input: Vec<(Span, TokenKind)>) -> ParseResult<MethodModifier
             TokenKind::Protected => Ok(MethodModifier::Protected(*span)),
             TokenKind::Public => Ok(MethodModifier::Public(*span)),
             TokenKind::Static => Ok(MethodModifier::Static(*span)),
-            _ => Err(error::modifier_cannot_be_used_for_class_method(
+            _ => Err(Box::new(error::modifier_cannot_be_used_for_class_method(
                 token.to_string(),
                 *span,
-            )),
+            ))),
         })
         .collect::<ParseResult<Vec<MethodModifier>>>()?;
 
@@ -108,9 +111,8 @@ pub fn interface_method_group(input: Vec<(Span, TokenKind)>) -> ParseResult<Meth
         .map(|(span, token)| match token {
             TokenKind::Public => Ok(MethodModifier::Public(*span)),
             TokenKind::Static => Ok(MethodModifier::Static(*span)),
-            _ => Err(error::modifier_cannot_be_used_for_interface_method(
-                token.to_string(),
-                *span,
+            _ => Err(Box::new(
+                error::modifier_cannot_be_used_for_interface_method(token.to_string(), *span),
             )),
         })
         .collect::<ParseResult<Vec<MethodModifier>>>()?;
@@ -128,10 +130,10 @@ pub fn enum_method_group(input: Vec<(Span, TokenKind)>) -> ParseResult<MethodMod
             TokenKind::Protected => Ok(MethodModifier::Protected(*span)),
             TokenKind::Public => Ok(MethodModifier::Public(*span)),
             TokenKind::Static => Ok(MethodModifier::Static(*span)),
-            _ => Err(error::modifier_cannot_be_used_for_enum_method(
+            _ => Err(Box::new(error::modifier_cannot_be_used_for_enum_method(
                 token.to_string(),
                 *span,
-            )),
+            ))),
         })
         .collect::<ParseResult<Vec<MethodModifier>>>()?;
 
@@ -148,10 +150,10 @@ pub fn property_group(input: Vec<(Span, TokenKind)>) -> ParseResult<PropertyModi
             TokenKind::Public => Ok(PropertyModifier::Public(*span)),
             TokenKind::Protected => Ok(PropertyModifier::Protected(*span)),
             TokenKind::Private => Ok(PropertyModifier::Private(*span)),
-            _ => Err(error::modifier_cannot_be_used_for_property(
+            _ => Err(Box::new(error::modifier_cannot_be_used_for_property(
                 token.to_string(),
                 *span,
-            )),
+            ))),
         })
         .collect::<ParseResult<Vec<PropertyModifier>>>()?;
 
@@ -169,9 +171,8 @@ pub fn promoted_property_group(
             TokenKind::Private => Ok(PromotedPropertyModifier::Private(*span)),
             TokenKind::Protected => Ok(PromotedPropertyModifier::Protected(*span)),
             TokenKind::Public => Ok(PromotedPropertyModifier::Public(*span)),
-            _ => Err(error::modifier_cannot_be_used_for_promoted_property(
-                token.to_string(),
-                *span,
+            _ => Err(Box::new(
+                error::modifier_cannot_be_used_for_promoted_property(token.to_string(), *span),
             )),
         })
         .collect::<ParseResult<Vec<PromotedPropertyModifier>>>()?;
@@ -191,28 +192,30 @@ pub fn constant_group(input: Vec<(Span, TokenKind)>) -> ParseResult<ConstantModi
             TokenKind::Private => {
                 private_span = Some(*span);
                 if let Some(final_span) = final_span {
-                    Err(error::final_and_private_modifiers_combined_for_constant(
-                        final_span, *span,
+                    Err(Box::new(
+                        error::final_and_private_modifiers_combined_for_constant(final_span, *span),
                     ))
                 } else {
-                    Ok(ConstantModifier::Final(*span))
+                    Ok(ConstantModifier::Private(*span))
                 }
             }
             TokenKind::Final => {
                 final_span = Some(*span);
                 if let Some(private_span) = private_span {
-                    Err(error::final_and_private_modifiers_combined_for_constant(
-                        *span,
-                        private_span,
+                    Err(Box::new(
+                        error::final_and_private_modifiers_combined_for_constant(
+                            *span,
+                            private_span,
+                        ),
                     ))
                 } else {
                     Ok(ConstantModifier::Final(*span))
                 }
             }
-            _ => Err(error::modifier_cannot_be_used_for_constant(
+            _ => Err(Box::new(error::modifier_cannot_be_used_for_constant(
                 token.to_string(),
                 *span,
-            )),
+            ))),
         })
         .collect::<ParseResult<Vec<ConstantModifier>>>()?;
 
@@ -227,9 +230,8 @@ pub fn interface_constant_group(
         .map(|(span, token)| match token {
             TokenKind::Public => Ok(ConstantModifier::Public(*span)),
             TokenKind::Final => Ok(ConstantModifier::Final(*span)),
-            _ => Err(error::modifier_cannot_be_used_for_interface_constant(
-                token.to_string(),
-                *span,
+            _ => Err(Box::new(
+                error::modifier_cannot_be_used_for_interface_constant(token.to_string(), *span),
             )),
         })
         .collect::<ParseResult<Vec<ConstantModifier>>>()?;
@@ -237,6 +239,22 @@ pub fn interface_constant_group(
     Ok(ConstantModifierGroup { modifiers })
 }
 
+/// Record an error if the collected modifiers contain `readonly`, but the
+/// targeted PHP version predates readonly support for the given `feature`.
+pub fn require_readonly(
+    state: &mut State,
+    modifiers: &[(Span, TokenKind)],
+    version: PhpVersion,
+    feature: &str,
+) {
+    if let Some((span, _)) = modifiers
+        .iter()
+        .find(|(_, kind)| kind == &TokenKind::Readonly)
+    {
+        state.require(version, feature, *span, "readonly".len());
+    }
+}
+
 pub fn collect(state: &mut State) -> ParseResult<Vec<(Span, TokenKind)>> {
     let mut collected: Vec<(Span, TokenKind)> = vec![];
 
@@ -256,11 +274,11 @@ pub fn collect(state: &mut State) -> ParseResult<Vec<(Span, TokenKind)>> {
 
     while collectable_tokens.contains(&current_kind) {
         if let Some((span, _)) = collected.iter().find(|(_, kind)| kind == &current_kind) {
-            return Err(error::multiple_modifiers(
+            return Err(Box::new(error::multiple_modifiers(
                 current_kind.to_string(),
                 *span,
                 current_span,
-            ));
+            )));
         }
 
         // guard against multiple visibility modifiers, we don't care where these modifiers are used.
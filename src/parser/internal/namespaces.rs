@@ -10,6 +10,7 @@ use crate::parser::ast::Block;
 use crate::parser::ast::Statement;
 use crate::parser::error;
 use crate::parser::error::ParseResult;
+use crate::parser::internal::blocks;
 use crate::parser::internal::identifiers;
 use crate::parser::internal::utils;
 use crate::parser::state::NamespaceType;
@@ -26,8 +27,8 @@ pub fn namespace(state: &mut State) -> ParseResult<Statement> {
     if let Some(name) = &name {
         if current.kind != TokenKind::LeftBrace {
             if let Some(NamespaceType::Braced) = state.namespace_type() {
-                return Err(error::unbraced_namespace_declarations_in_braced_context(
-                    current.span,
+                return Err(Box::new(
+                    error::unbraced_namespace_declarations_in_braced_context(current.span),
                 ));
             }
 
@@ -36,11 +37,11 @@ pub fn namespace(state: &mut State) -> ParseResult<Statement> {
     }
 
     match state.namespace_type() {
-        Some(NamespaceType::Unbraced) => Err(
+        Some(NamespaceType::Unbraced) => Err(Box::new(
             error::braced_namespace_declarations_in_unbraced_context(current.span),
-        ),
+        )),
         Some(NamespaceType::Braced) if state.namespace().is_some() => {
-            Err(error::nested_namespace_declarations(start))
+            Err(Box::new(error::nested_namespace_declarations(start)))
         }
         _ => braced_namespace(state, start, name),
     }
@@ -51,7 +52,7 @@ fn unbraced_namespace(
     start: Span,
     name: SimpleIdentifier,
 ) -> ParseResult<Statement> {
-    let end = utils::skip_semicolon(state)?;
+    let end = utils::skip_ending(state)?;
 
     let statements = scoped!(state, Scope::Namespace(name.clone()), {
         let mut statements = Block::new();
@@ -59,9 +60,13 @@ fn unbraced_namespace(
         // `namespace` token as a top level statement, this namespace scope ends.
         // otherwise we will end up with nested namespace statements.
         while state.stream.current().kind != TokenKind::Namespace && !state.stream.is_eof() {
-            statements.push(parser::top_level_statement(state)?);
+            blocks::comments(state, &mut statements);
+
+            statements.push(parser::recoverable(state, parser::top_level_statement)?);
         }
 
+        blocks::trailing_comments(state, &mut statements);
+
         statements
     });
 
@@ -69,7 +74,7 @@ fn unbraced_namespace(
         UnbracedNamespace {
             start,
             end,
-            name,
+            name: name.to_name(),
             statements,
         },
     )))
@@ -85,9 +90,13 @@ fn braced_namespace(
 
         let mut statements = Block::new();
         while state.stream.current().kind != TokenKind::RightBrace && !state.stream.is_eof() {
-            statements.push(parser::top_level_statement(state)?);
+            blocks::comments(state, &mut statements);
+
+            statements.push(parser::recoverable(state, parser::top_level_statement)?);
         }
 
+        blocks::trailing_comments(state, &mut statements);
+
         let end = utils::skip_right_brace(state)?;
 
         BracedNamespaceBody {
@@ -100,7 +109,7 @@ fn braced_namespace(
     Ok(Statement::Namespace(NamespaceStatement::Braced(
         BracedNamespace {
             namespace: span,
-            name,
+            name: name.map(|name| name.to_name()),
             body,
         },
     )))
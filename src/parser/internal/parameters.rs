@@ -1,3 +1,4 @@
+use crate::lexer::token::Span;
 use crate::lexer::token::TokenKind;
 use crate::parser::ast::arguments::{Argument, SingleArgument};
 use crate::parser::ast::arguments::{ArgumentList, NamedArgument, PositionalArgument};
@@ -6,8 +7,9 @@ use crate::parser::ast::functions::ConstructorParameterList;
 use crate::parser::ast::functions::FunctionParameter;
 use crate::parser::ast::functions::FunctionParameterList;
 use crate::parser::ast::identifiers::SimpleIdentifier;
+use crate::parser::ast::utils::CommaSeparated;
+use crate::parser::config::PhpVersion;
 use crate::parser::error;
-use crate::parser::error::ParseError;
 use crate::parser::error::ParseResult;
 use crate::parser::expressions;
 use crate::parser::internal::attributes;
@@ -18,7 +20,7 @@ use crate::parser::internal::utils;
 use crate::parser::internal::variables;
 use crate::parser::state::State;
 
-pub fn function_parameter_list(state: &mut State) -> Result<FunctionParameterList, ParseError> {
+pub fn function_parameter_list(state: &mut State) -> ParseResult<FunctionParameterList> {
     let comments = state.stream.comments();
     let left_parenthesis = utils::skip_left_parenthesis(state)?;
     let parameters = utils::comma_separated(
@@ -27,6 +29,11 @@ pub fn function_parameter_list(state: &mut State) -> Result<FunctionParameterLis
             attributes::gather_attributes(state)?;
 
             let ty = data_type::optional_data_type(state)?;
+            if let Some(ty) = &ty {
+                if ty.is_bottom() {
+                    state.record(error::bottom_type_used_in_parameter(ty));
+                }
+            }
 
             let mut current = state.stream.current();
             let ampersand = if current.kind == TokenKind::Ampersand {
@@ -51,7 +58,7 @@ pub fn function_parameter_list(state: &mut State) -> Result<FunctionParameterLis
             let mut default = None;
             if state.stream.current().kind == TokenKind::Equals {
                 state.stream.next();
-                default = Some(expressions::create(state)?);
+                default = Some(state.initializer(expressions::create)?);
             }
 
             Ok(FunctionParameter {
@@ -67,8 +74,12 @@ pub fn function_parameter_list(state: &mut State) -> Result<FunctionParameterLis
         TokenKind::RightParen,
     )?;
 
+    require_trailing_comma(state, &parameters);
+
     let right_parenthesis = utils::skip_right_parenthesis(state)?;
 
+    ensure_variadic_is_last(state, &parameters);
+
     Ok(FunctionParameterList {
         comments,
         left_parenthesis,
@@ -80,7 +91,7 @@ pub fn function_parameter_list(state: &mut State) -> Result<FunctionParameterLis
 pub fn constructor_parameter_list(
     state: &mut State,
     class: Option<&SimpleIdentifier>,
-) -> Result<ConstructorParameterList, ParseError> {
+) -> ParseResult<ConstructorParameterList> {
     let comments = state.stream.comments();
 
     let left_parenthesis = utils::skip_left_parenthesis(state)?;
@@ -89,7 +100,14 @@ pub fn constructor_parameter_list(
         &|state| {
             attributes::gather_attributes(state)?;
 
-            let modifiers = modifiers::promoted_property_group(modifiers::collect(state)?)?;
+            let modifiers = modifiers::collect(state)?;
+            modifiers::require_readonly(
+                state,
+                &modifiers,
+                PhpVersion::Php81,
+                "readonly properties",
+            );
+            let modifiers = modifiers::promoted_property_group(modifiers)?;
 
             let ty = data_type::optional_data_type(state)?;
 
@@ -108,13 +126,13 @@ pub fn constructor_parameter_list(
                 state.stream.next();
                 let var = variables::simple_variable(state)?;
                 if !modifiers.is_empty() {
-                    return Err(error::variadic_promoted_property(
+                    return Err(Box::new(error::variadic_promoted_property(
                         state,
                         class,
                         &var,
                         current.span,
                         modifiers.modifiers.first().unwrap(),
-                    ));
+                    )));
                 }
 
                 (Some(current.span), var)
@@ -124,26 +142,32 @@ pub fn constructor_parameter_list(
 
             // 2. Then expect a variable.
 
-            if !modifiers.is_empty() {
+            if modifiers.is_empty() {
+                if let Some(ty) = &ty {
+                    if ty.is_bottom() {
+                        state.record(error::bottom_type_used_in_parameter(ty));
+                    }
+                }
+            } else {
                 match &ty {
                     Some(ty) => {
                         if ty.includes_callable() || ty.is_bottom() {
-                            return Err(error::forbidden_type_used_in_property(
+                            return Err(Box::new(error::forbidden_type_used_in_property(
                                 state,
                                 class,
                                 &var,
                                 ty.clone(),
-                            ));
+                            )));
                         }
                     }
                     None => {
                         if let Some(modifier) = modifiers.get_readonly() {
-                            return Err(error::missing_type_for_readonly_property(
+                            return Err(Box::new(error::missing_type_for_readonly_property(
                                 state,
                                 class,
                                 &var,
                                 modifier.span(),
-                            ));
+                            )));
                         }
                     }
                 }
@@ -152,7 +176,7 @@ pub fn constructor_parameter_list(
             let mut default = None;
             if state.stream.current().kind == TokenKind::Equals {
                 state.stream.next();
-                default = Some(expressions::create(state)?);
+                default = Some(state.initializer(expressions::create)?);
             }
 
             Ok(ConstructorParameter {
@@ -169,8 +193,12 @@ pub fn constructor_parameter_list(
         TokenKind::RightParen,
     )?;
 
+    require_trailing_comma(state, &parameters);
+
     let right_parenthesis = utils::skip_right_parenthesis(state)?;
 
+    ensure_variadic_is_last(state, &parameters);
+
     Ok(ConstructorParameterList {
         comments,
         left_parenthesis,
@@ -192,9 +220,11 @@ pub fn argument_list(state: &mut State) -> ParseResult<ArgumentList> {
         if named {
             has_used_named_arguments = true;
         } else if has_used_named_arguments {
-            return Err(error::cannot_use_positional_argument_after_named_argument(
-                span,
-                state.stream.current().span,
+            return Err(Box::new(
+                error::cannot_use_positional_argument_after_named_argument(
+                    span,
+                    state.stream.current().span,
+                ),
             ));
         }
 
@@ -217,6 +247,10 @@ pub fn argument_list(state: &mut State) -> ParseResult<ArgumentList> {
     })
 }
 
+/// Parse a parenthesized list holding a single argument.
+///
+/// Returns `None` if there is no opening parenthesis, or if the list is empty
+/// and the argument is not required.
 pub fn single_argument(
     state: &mut State,
     required: bool,
@@ -229,19 +263,21 @@ pub fn single_argument(
 
     while !state.stream.is_eof() && state.stream.current().kind != TokenKind::RightParen {
         let span = state.stream.current().span;
-        let (named, argument) = argument(state).ok()?;
+        let (named, argument) = match argument(state) {
+            Ok(argument) => argument,
+            Err(error) => return Some(Err(error)),
+        };
         if only_positional && named {
-            return Some(Err(error::only_positional_arguments_are_accepted(
-                span,
-                state.stream.current().span,
+            return Some(Err(Box::new(
+                error::only_positional_arguments_are_accepted(span, state.stream.current().span),
             )));
         }
 
         if first_argument.is_some() {
-            return Some(Err(error::only_one_argument_is_accepted(
+            return Some(Err(Box::new(error::only_one_argument_is_accepted(
                 span,
                 state.stream.current().span,
-            )));
+            ))));
         }
 
         first_argument = Some(argument);
@@ -254,13 +290,16 @@ pub fn single_argument(
     }
 
     if required && first_argument.is_none() {
-        return Some(Err(error::argument_is_required(
+        return Some(Err(Box::new(error::argument_is_required(
             state.stream.current().span,
             state.stream.current().span,
-        )));
+        ))));
     }
 
-    let end = utils::skip_right_parenthesis(state).ok()?;
+    let end = match utils::skip_right_parenthesis(state) {
+        Ok(end) => end,
+        Err(error) => return Some(Err(error)),
+    };
 
     first_argument.as_ref()?;
 
@@ -277,6 +316,12 @@ fn argument(state: &mut State) -> ParseResult<(bool, Argument)> {
         && state.stream.peek().kind == TokenKind::Colon
     {
         let name = identifiers::identifier_maybe_reserved(state)?;
+        state.require(
+            PhpVersion::Php80,
+            "named arguments",
+            name.span,
+            name.value.len(),
+        );
         let colon = utils::skip(state, TokenKind::Colon)?;
         let ellipsis = if state.stream.current().kind == TokenKind::Ellipsis {
             Some(utils::skip(state, TokenKind::Ellipsis)?)
@@ -314,3 +359,52 @@ fn argument(state: &mut State) -> ParseResult<(bool, Argument)> {
         }),
     ))
 }
+
+fn require_trailing_comma<T>(state: &mut State, parameters: &CommaSeparated<T>) {
+    if let Some(comma) = parameters.trailing_comma() {
+        state.require(
+            PhpVersion::Php80,
+            "trailing commas in parameter lists",
+            comma,
+            1,
+        );
+    }
+}
+
+fn ensure_variadic_is_last<T: Parameter>(state: &mut State, parameters: &CommaSeparated<T>) {
+    // only the last parameter may be variadic.
+    let variadic = parameters.inner[..parameters.inner.len().saturating_sub(1)]
+        .iter()
+        .find(|parameter| parameter.is_variadic());
+
+    if let Some(ellipsis) = variadic.and_then(|parameter| parameter.ellipsis()) {
+        state.record(error::variadic_parameter_not_last(ellipsis));
+    }
+}
+
+/// The parts shared by function and constructor parameters.
+trait Parameter {
+    fn is_variadic(&self) -> bool;
+
+    fn ellipsis(&self) -> Option<Span>;
+}
+
+impl Parameter for FunctionParameter {
+    fn is_variadic(&self) -> bool {
+        FunctionParameter::is_variadic(self)
+    }
+
+    fn ellipsis(&self) -> Option<Span> {
+        self.ellipsis
+    }
+}
+
+impl Parameter for ConstructorParameter {
+    fn is_variadic(&self) -> bool {
+        ConstructorParameter::is_variadic(self)
+    }
+
+    fn ellipsis(&self) -> Option<Span> {
+        self.ellipsis
+    }
+}
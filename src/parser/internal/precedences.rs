@@ -1,12 +1,14 @@
 use crate::lexer::token::TokenKind;
 
+/// How operators of equal precedence group, e.g. `a - b - c` is `(a - b) - c`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum Associativity {
     Non,
     Left,
     Right,
 }
 
-#[allow(dead_code)]
+/// The binding power of an operator, ordered from loosest to tightest.
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone)]
 pub enum Precedence {
     Lowest,
@@ -41,10 +43,11 @@ pub enum Precedence {
 }
 
 impl Precedence {
-    pub fn infix(kind: &TokenKind) -> Self {
+    /// The precedence of `kind` when used as a binary operator, if it is one.
+    pub fn infix(kind: &TokenKind) -> Option<Self> {
         use TokenKind::*;
 
-        match kind {
+        Some(match kind {
             Pow => Self::Pow,
             Instanceof => Self::Instanceof,
             Asterisk | Slash | Percent => Self::MulDivMod,
@@ -62,28 +65,48 @@ impl Precedence {
             DoubleQuestion => Self::NullCoalesce,
             Question | QuestionColon => Self::Ternary,
             Equals | PlusEquals | MinusEquals | AsteriskEquals | PowEquals | SlashEquals
-            | DotEquals | AndEquals | DoubleQuestionEquals | PercentEquals | AmpersandEquals
-            | PipeEquals | CaretEquals | LeftShiftEquals | RightShiftEquals => Self::Assignment,
-            Yield => Self::Yield,
+            | DotEquals | DoubleQuestionEquals | PercentEquals | AmpersandEquals | PipeEquals
+            | CaretEquals | LeftShiftEquals | RightShiftEquals => Self::Assignment,
             LogicalAnd => Self::KeyAnd,
             LogicalOr => Self::KeyOr,
             LogicalXor => Self::KeyXor,
-            _ => unimplemented!("precedence for op {:?}", kind),
-        }
+            _ => return None,
+        })
     }
 
-    pub fn postfix(kind: &TokenKind) -> Self {
+    /// The precedence of `kind` when it follows an operand, e.g. `$a++` or `$a->b`.
+    pub fn postfix(kind: &TokenKind) -> Option<Self> {
         use TokenKind::*;
 
-        match kind {
+        Some(match kind {
             DoubleQuestion => Self::NullCoalesce,
             Increment | Decrement => Self::IncDec,
             LeftParen | LeftBracket => Self::CallDim,
             Arrow | QuestionArrow | DoubleColon => Self::ObjectAccess,
-            _ => unimplemented!("postfix precedence for op {:?}", kind),
-        }
+            _ => return None,
+        })
+    }
+
+    /// The precedence of `kind` when it precedes an operand, e.g. `!$a` or `(int) $a`.
+    ///
+    /// This is the precedence the operand is parsed at, so `print`, `throw` and
+    /// `include` take everything that follows them.
+    pub fn prefix(kind: &TokenKind) -> Option<Self> {
+        use TokenKind::*;
+
+        Some(match kind {
+            Bang => Self::Bang,
+            Plus | Minus | Increment | Decrement | BitwiseNot | At | StringCast | BinaryCast
+            | ObjectCast | BoolCast | BooleanCast | IntCast | IntegerCast | FloatCast
+            | DoubleCast | RealCast | UnsetCast | ArrayCast => Self::Prefix,
+            Clone | New => Self::CloneOrNew,
+            Yield => Self::Yield,
+            Print | Throw | Include | IncludeOnce | Require | RequireOnce => Self::Lowest,
+            _ => return None,
+        })
     }
 
+    /// How operators at this precedence group, or `None` if they cannot be chained.
     pub fn associativity(&self) -> Option<Associativity> {
         Some(match self {
             Self::Instanceof
@@ -105,3 +128,64 @@ impl Precedence {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_infix_precedence() {
+        assert_eq!(
+            Precedence::infix(&TokenKind::Plus),
+            Some(Precedence::AddSub)
+        );
+        assert!(Precedence::infix(&TokenKind::Asterisk) > Precedence::infix(&TokenKind::Plus));
+        assert!(
+            Precedence::infix(&TokenKind::BooleanAnd) > Precedence::infix(&TokenKind::LogicalAnd)
+        );
+        assert_eq!(Precedence::infix(&TokenKind::Bang), None);
+        assert_eq!(Precedence::infix(&TokenKind::SemiColon), None);
+    }
+
+    #[test]
+    fn test_prefix_precedence() {
+        assert_eq!(Precedence::prefix(&TokenKind::Bang), Some(Precedence::Bang));
+        assert_eq!(
+            Precedence::prefix(&TokenKind::IntCast),
+            Some(Precedence::Prefix)
+        );
+        assert_eq!(
+            Precedence::prefix(&TokenKind::New),
+            Some(Precedence::CloneOrNew)
+        );
+        assert_eq!(
+            Precedence::prefix(&TokenKind::Print),
+            Some(Precedence::Lowest)
+        );
+        assert!(Precedence::prefix(&TokenKind::At) > Precedence::infix(&TokenKind::Asterisk));
+        assert_eq!(Precedence::prefix(&TokenKind::Dot), None);
+    }
+
+    #[test]
+    fn test_postfix_precedence() {
+        assert_eq!(
+            Precedence::postfix(&TokenKind::Arrow),
+            Some(Precedence::ObjectAccess)
+        );
+        assert_eq!(Precedence::postfix(&TokenKind::Bang), None);
+    }
+
+    #[test]
+    fn test_associativity() {
+        assert_eq!(
+            Precedence::AddSub.associativity(),
+            Some(Associativity::Left)
+        );
+        assert_eq!(Precedence::Pow.associativity(), Some(Associativity::Right));
+        assert_eq!(
+            Precedence::Equality.associativity(),
+            Some(Associativity::Non)
+        );
+        assert_eq!(Precedence::Prefix.associativity(), None);
+    }
+}
@@ -2,6 +2,7 @@ use crate::expect_token;
 use crate::expected_token_err;
 use crate::lexer::error::SyntaxError;
 use crate::lexer::token::DocStringIndentationKind;
+use crate::lexer::token::Span;
 use crate::lexer::token::TokenKind;
 use crate::parser::ast::identifiers::Identifier;
 use crate::parser::ast::literals::Literal;
@@ -82,45 +83,41 @@ pub fn heredoc(state: &mut State) -> ParseResult<Expression> {
 
     state.stream.next();
 
-    let mut new_line = true;
     if indentation_type != DocStringIndentationKind::None {
         let indentation_char: u8 = indentation_type.into();
 
+        // We only need to strip and validate indentation at the
+        // start of each line, so we need to keep track of whether
+        // the current part starts on a new line.
+        let mut new_line = true;
         for part in parts.iter_mut() {
-            // We only need to strip and validate indentation
-            // for individual lines, so we can skip checks if
-            // we know we're not on a new line.
-            if !new_line {
-                continue;
-            }
-
             match part {
                 StringPart::Literal(LiteralStringPart { value: bytes }) => {
-                    // 1. If this line doesn't start with any whitespace,
-                    //    we can return an error early because we know
-                    //    the label was indented.
-                    if !bytes.starts_with(&[b' ']) && !bytes.starts_with(&[b'\t']) {
-                        return Err(SyntaxError::InvalidDocBodyIndentationLevel(
-                            indentation_amount,
-                            span,
-                        )
-                        .into());
-                    }
+                    let mut stripped = Vec::with_capacity(bytes.len());
+                    for (i, line) in bytes.split(|b| *b == b'\n').enumerate() {
+                        if i > 0 {
+                            stripped.push(b'\n');
+                        }
 
-                    // 2. If this line doesn't start with the correct
-                    //    type of whitespace, we can also return an error.
-                    if !bytes.starts_with(&[indentation_char]) {
-                        return Err(SyntaxError::InvalidDocIndentation(span).into());
+                        if i == 0 && !new_line {
+                            stripped.extend(line);
+                        } else {
+                            stripped.extend(strip_indentation(
+                                line,
+                                indentation_char,
+                                indentation_amount,
+                                span,
+                            )?);
+                        }
                     }
 
-                    // 3. We now know that the whitespace at the start of
-                    //    this line is correct, so we need to check that the
-                    //    amount of whitespace is correct too. In this case,
-                    //    the amount of whitespace just needs to be at least
-                    //    the same, so we can create a vector containing the
-                    //    minimum and check using `starts_with()`.
-                    let expected_whitespace_buffer = vec![indentation_char; indentation_amount];
-                    if !bytes.starts_with(&expected_whitespace_buffer) {
+                    new_line = stripped.ends_with(&[b'\n']);
+                    *bytes = stripped.into();
+                }
+                _ => {
+                    // An expression at the start of a line means the
+                    // line isn't indented at all.
+                    if new_line && indentation_amount > 0 {
                         return Err(SyntaxError::InvalidDocBodyIndentationLevel(
                             indentation_amount,
                             span,
@@ -128,16 +125,8 @@ pub fn heredoc(state: &mut State) -> ParseResult<Expression> {
                         .into());
                     }
 
-                    // 4. All of the above checks have passed, so we know
-                    //    there are no more possible errors. Let's now
-                    //    strip the leading whitespace accordingly.
-                    *bytes = bytes
-                        .strip_prefix(&expected_whitespace_buffer[..])
-                        .unwrap()
-                        .into();
-                    new_line = bytes.ends_with(&[b'\n']);
+                    new_line = false;
                 }
-                _ => continue,
             }
         }
     }
@@ -167,60 +156,20 @@ pub fn nowdoc(state: &mut State) -> ParseResult<Expression> {
     if indentation_type != DocStringIndentationKind::None {
         let indentation_char: u8 = indentation_type.into();
 
-        let mut lines = string_part
-            .split(|b| *b == b'\n')
-            .map(|s| s.to_vec())
-            .collect::<Vec<Vec<u8>>>();
-
-        for line in lines.iter_mut() {
-            if line.is_empty() {
-                continue;
-            }
-
-            // 1. If this line doesn't start with any whitespace,
-            //    we can return an error early because we know
-            //    the label was indented.
-            if !line.starts_with(&[b' ']) && !line.starts_with(&[b'\t']) {
-                return Err(
-                    SyntaxError::InvalidDocBodyIndentationLevel(indentation_amount, span).into(),
-                );
-            }
-
-            // 2. If this line doesn't start with the correct
-            //    type of whitespace, we can also return an error.
-            if !line.starts_with(&[indentation_char]) {
-                return Err(SyntaxError::InvalidDocIndentation(span).into());
-            }
-
-            // 3. We now know that the whitespace at the start of
-            //    this line is correct, so we need to check that the
-            //    amount of whitespace is correct too. In this case,
-            //    the amount of whitespace just needs to be at least
-            //    the same, so we can create a vector containing the
-            //    minimum and check using `starts_with()`.
-            let expected_whitespace_buffer = vec![indentation_char; indentation_amount];
-            if !line.starts_with(&expected_whitespace_buffer) {
-                return Err(
-                    SyntaxError::InvalidDocBodyIndentationLevel(indentation_amount, span).into(),
-                );
+        let mut bytes = Vec::with_capacity(string_part.len());
+        for (i, line) in string_part.split(|b| *b == b'\n').enumerate() {
+            if i > 0 {
+                bytes.push(b'\n');
             }
 
-            // 4. All of the above checks have passed, so we know
-            //    there are no more possible errors. Let's now
-            //    strip the leading whitespace accordingly.
-            *line = line
-                .strip_prefix(&expected_whitespace_buffer[..])
-                .unwrap()
-                .into();
+            bytes.extend(strip_indentation(
+                line,
+                indentation_char,
+                indentation_amount,
+                span,
+            )?);
         }
 
-        let mut bytes = Vec::new();
-        for (i, line) in lines.iter().enumerate() {
-            bytes.extend(line);
-            if i < lines.len() - 1 {
-                bytes.push(b'\n');
-            }
-        }
         string_part = bytes.into();
     }
 
@@ -230,6 +179,36 @@ pub fn nowdoc(state: &mut State) -> ParseResult<Expression> {
     }))
 }
 
+/// Strip the indentation of a doc string's closing label from a line
+/// of its body.
+///
+/// Every line must be indented using at least the same amount of the
+/// same type of whitespace as the closing label, apart from lines that
+/// only contain whitespace.
+fn strip_indentation(
+    line: &[u8],
+    indentation_char: u8,
+    indentation_amount: usize,
+    span: Span,
+) -> ParseResult<&[u8]> {
+    let mut length = 0;
+    while length < indentation_amount && length < line.len() {
+        match line[length] {
+            b' ' | b'\t' if line[length] != indentation_char => {
+                return Err(SyntaxError::InvalidDocIndentation(span).into());
+            }
+            b' ' | b'\t' => length += 1,
+            _ => break,
+        }
+    }
+
+    if length < indentation_amount && length < line.len() {
+        return Err(SyntaxError::InvalidDocBodyIndentationLevel(indentation_amount, span).into());
+    }
+
+    Ok(&line[length..])
+}
+
 fn part(state: &mut State) -> ParseResult<Option<StringPart>> {
     Ok(match &state.stream.current().kind {
         TokenKind::StringPart => {
@@ -315,7 +294,7 @@ fn part(state: &mut State) -> ParseResult<Option<StringPart>> {
                         )),
                         _ => {
                             return expected_token_err!(
-                                ["`-`", "an integer", "an identifier", "a variable"],
+                                ["-", "an integer", "an identifier", "a variable"],
                                 state
                             );
                         }
@@ -359,7 +338,7 @@ fn part(state: &mut State) -> ParseResult<Option<StringPart>> {
             }))
         }
         _ => {
-            return expected_token_err!(["`${`", "`{$", "`\"`", "a variable"], state);
+            return expected_token_err!(["${", "{$", "\"", "a variable"], state);
         }
     })
 }
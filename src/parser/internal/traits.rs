@@ -1,6 +1,7 @@
 use crate::expect_token;
 use crate::lexer::token::Token;
 use crate::lexer::token::TokenKind;
+use crate::parser::ast::identifiers::Name;
 use crate::parser::ast::identifiers::SimpleIdentifier;
 use crate::parser::ast::modifiers::VisibilityModifier;
 use crate::parser::ast::traits::TraitBody;
@@ -9,6 +10,7 @@ use crate::parser::ast::traits::TraitStatement;
 use crate::parser::ast::traits::TraitUsage;
 use crate::parser::ast::traits::TraitUsageAdaptation;
 use crate::parser::ast::Statement;
+use crate::parser::config::PhpVersion;
 use crate::parser::error::ParseResult;
 use crate::parser::internal::attributes;
 use crate::parser::internal::constants;
@@ -30,7 +32,7 @@ pub fn usage(state: &mut State) -> ParseResult<TraitUsage> {
     while state.stream.current().kind != TokenKind::SemiColon
         && state.stream.current().kind != TokenKind::LeftBrace
     {
-        let t = identifiers::full_type_name(state)?;
+        let t = identifiers::full_type_name(state)?.to_name();
         traits.push(t);
 
         if state.stream.current().kind == TokenKind::Comma {
@@ -55,16 +57,16 @@ pub fn usage(state: &mut State) -> ParseResult<TraitUsage> {
         utils::skip_left_brace(state)?;
 
         while state.stream.current().kind != TokenKind::RightBrace {
-            let (r#trait, method): (Option<SimpleIdentifier>, SimpleIdentifier) =
-                match state.stream.peek().kind {
-                    TokenKind::DoubleColon => {
-                        let r#trait = identifiers::full_type_name(state)?;
-                        state.stream.next();
-                        let method = identifiers::identifier(state)?;
-                        (Some(r#trait), method)
-                    }
-                    _ => (None, identifiers::identifier(state)?),
-                };
+            let (r#trait, method): (Option<Name>, SimpleIdentifier) = match state.stream.peek().kind
+            {
+                TokenKind::DoubleColon => {
+                    let r#trait = identifiers::full_type_name(state)?.to_name();
+                    state.stream.next();
+                    let method = identifiers::identifier(state)?;
+                    (Some(r#trait), method)
+                }
+                _ => (None, identifiers::identifier(state)?),
+            };
 
             expect_token!([
                     TokenKind::As => {
@@ -74,7 +76,7 @@ pub fn usage(state: &mut State) -> ParseResult<TraitUsage> {
                                     TokenKind::Public => VisibilityModifier::Public(*span),
                                     TokenKind::Protected => VisibilityModifier::Protected(*span),
                                     TokenKind::Private => VisibilityModifier::Private(*span),
-                                ], state, ["`private`", "`protected`", "`public`"]);
+                                ], state, ["private", "protected", "public"]);
 
                                 state.stream.next();
 
@@ -107,7 +109,7 @@ pub fn usage(state: &mut State) -> ParseResult<TraitUsage> {
                     },
                     TokenKind::Insteadof => {
                         let mut insteadof = vec![
-                            identifiers::full_type_name(state)?
+                            identifiers::full_type_name(state)?.to_name()
                         ];
 
                         if state.stream.current().kind == TokenKind::Comma {
@@ -120,7 +122,7 @@ pub fn usage(state: &mut State) -> ParseResult<TraitUsage> {
                             state.stream.next();
 
                             while state.stream.current().kind != TokenKind::SemiColon {
-                                insteadof.push(identifiers::full_type_name(state)?);
+                                insteadof.push(identifiers::full_type_name(state)?.to_name());
 
                                 if state.stream.current().kind == TokenKind::Comma {
                                     if state.stream.peek().kind == TokenKind::SemiColon {
@@ -142,7 +144,7 @@ pub fn usage(state: &mut State) -> ParseResult<TraitUsage> {
                             insteadof,
                         });
                     }
-                ], state, ["`as`", "`insteadof`"]);
+                ], state, ["as", "insteadof"]);
 
             utils::skip_semicolon(state)?;
         }
@@ -160,6 +162,7 @@ pub fn usage(state: &mut State) -> ParseResult<TraitUsage> {
 }
 
 pub fn parse(state: &mut State) -> ParseResult<Statement> {
+    let comments = state.stream.comments();
     let span = utils::skip(state, TokenKind::Trait)?;
     let name = identifiers::type_identifier(state)?;
     let attributes = state.get_attributes();
@@ -177,6 +180,7 @@ pub fn parse(state: &mut State) -> ParseResult<Statement> {
     };
 
     Ok(Statement::Trait(TraitStatement {
+        comments,
         r#trait: span,
         name,
         attributes,
@@ -218,6 +222,7 @@ fn member(state: &mut State, class_name: &SimpleIdentifier) -> ParseResult<Trait
         };
     }
 
+    modifiers::require_readonly(state, &modifiers, PhpVersion::Php81, "readonly properties");
     properties::parse(
         state,
         Some(class_name),
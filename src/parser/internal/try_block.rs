@@ -78,7 +78,10 @@ pub fn try_block(state: &mut State) -> ParseResult<Statement> {
     }
 
     if catches.is_empty() && finally.is_none() {
-        return Err(error::try_without_catch_or_finally(start, last_right_brace));
+        return Err(Box::new(error::try_without_catch_or_finally(
+            start,
+            last_right_brace,
+        )));
     }
 
     let end = state.stream.current().span;
@@ -103,6 +106,14 @@ fn catch_type(state: &mut State) -> ParseResult<CatchType> {
 
         while !state.stream.is_eof() {
             let id = identifiers::full_name(state)?;
+
+            if let Some(previous) = types
+                .iter()
+                .find(|previous| previous.value.eq_ignore_ascii_case(&id.value))
+            {
+                state.record(error::duplicate_catch_type(previous, &id));
+            }
+
             types.push(id);
 
             if state.stream.current().kind != TokenKind::Pipe {
@@ -112,8 +123,12 @@ fn catch_type(state: &mut State) -> ParseResult<CatchType> {
             state.stream.next();
         }
 
-        return Ok(CatchType::Union { identifiers: types });
+        return Ok(CatchType::Union {
+            identifiers: types.iter().map(|id| id.to_name()).collect(),
+        });
     }
 
-    Ok(CatchType::Identifier { identifier: id })
+    Ok(CatchType::Identifier {
+        identifier: id.to_name(),
+    })
 }
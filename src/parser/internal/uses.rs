@@ -26,17 +26,17 @@ pub fn use_statement(state: &mut State) -> ParseResult<Statement> {
     };
 
     if state.stream.peek().kind == TokenKind::LeftBrace {
-        let prefix = identifiers::full_name(state)?;
+        let prefix = identifiers::full_name(state)?.to_name();
         state.stream.next();
         let mut uses = Vec::new();
         while state.stream.current().kind != TokenKind::RightBrace {
             let use_kind = match state.stream.current().kind {
                 TokenKind::Function => {
                     if kind != UseKind::Normal {
-                        return Err(error::unexpected_token(
+                        return Err(Box::new(error::unexpected_token(
                             vec!["an identifier".to_string()],
                             state.stream.current(),
-                        ));
+                        )));
                     }
 
                     state.stream.next();
@@ -44,10 +44,10 @@ pub fn use_statement(state: &mut State) -> ParseResult<Statement> {
                 }
                 TokenKind::Const => {
                     if kind != UseKind::Normal {
-                        return Err(error::unexpected_token(
+                        return Err(Box::new(error::unexpected_token(
                             vec!["an identifier".to_string()],
                             state.stream.current(),
-                        ));
+                        )));
                     }
 
                     state.stream.next();
@@ -56,7 +56,7 @@ pub fn use_statement(state: &mut State) -> ParseResult<Statement> {
                 _ => None,
             };
 
-            let name = identifiers::full_type_name(state)?;
+            let name = identifiers::full_type_name(state)?.to_name();
             let mut alias = None;
             if state.stream.current().kind == TokenKind::As {
                 state.stream.next();
@@ -76,7 +76,7 @@ pub fn use_statement(state: &mut State) -> ParseResult<Statement> {
         }
 
         utils::skip_right_brace(state)?;
-        utils::skip_semicolon(state)?;
+        utils::skip_ending(state)?;
 
         Ok(Statement::GroupUse(GroupUseStatement {
             prefix,
@@ -86,7 +86,7 @@ pub fn use_statement(state: &mut State) -> ParseResult<Statement> {
     } else {
         let mut uses = Vec::new();
         while !state.stream.is_eof() {
-            let name = identifiers::full_type_name(state)?;
+            let name = identifiers::full_type_name(state)?.to_name();
             let mut alias = None;
             if state.stream.current().kind == TokenKind::As {
                 state.stream.next();
@@ -104,7 +104,7 @@ pub fn use_statement(state: &mut State) -> ParseResult<Statement> {
                 continue;
             }
 
-            utils::skip_semicolon(state)?;
+            utils::skip_ending(state)?;
             break;
         }
 
@@ -18,7 +18,10 @@ pub fn skip_ending(state: &mut State) -> ParseResult<Ending> {
 
         Ok(Ending::Semicolon(current.span))
     } else {
-        Err(error::unexpected_token(vec![";".to_string()], current))
+        Err(Box::new(error::unexpected_token(
+            vec![";".to_string()],
+            current,
+        )))
     }
 }
 
@@ -30,7 +33,10 @@ pub fn skip_semicolon(state: &mut State) -> ParseResult<Span> {
 
         Ok(current.span)
     } else {
-        Err(error::unexpected_token(vec!["`;`".to_string()], current))
+        Err(Box::new(error::unexpected_token(
+            vec![";".to_string()],
+            current,
+        )))
     }
 }
 
@@ -80,7 +86,10 @@ pub fn skip(state: &mut State, kind: TokenKind) -> ParseResult<Span> {
 
         Ok(end)
     } else {
-        Err(error::unexpected_token(vec![kind.to_string()], current))
+        Err(Box::new(error::unexpected_token(
+            vec![kind.to_string()],
+            current,
+        )))
     }
 }
 
@@ -94,10 +103,10 @@ pub fn skip_any_of(state: &mut State, kinds: &[TokenKind]) -> ParseResult<Span>
 
         Ok(end)
     } else {
-        Err(error::unexpected_token(
+        Err(Box::new(error::unexpected_token(
             kinds.iter().map(|kind| kind.to_string()).collect(),
             current,
-        ))
+        )))
     }
 }
 
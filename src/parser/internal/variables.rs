@@ -4,6 +4,7 @@ use crate::parser::ast::variables::BracedVariableVariable;
 use crate::parser::ast::variables::SimpleVariable;
 use crate::parser::ast::variables::Variable;
 use crate::parser::ast::variables::VariableVariable;
+use crate::parser::error;
 use crate::parser::error::ParseResult;
 use crate::parser::expressions;
 use crate::parser::internal::utils;
@@ -28,6 +29,10 @@ pub fn dynamic_variable(state: &mut State) -> ParseResult<Variable> {
         TokenKind::Variable => {
             let span = current.span;
             let name = current.value.clone();
+            if name == b"$this" && !state.in_object_context() {
+                state.record(error::this_outside_object_context(span));
+            }
+
             state.stream.next();
 
             Ok(Variable::SimpleVariable(SimpleVariable { span, name }))
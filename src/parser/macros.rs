@@ -35,10 +35,10 @@ macro_rules! expect_token {
                 },
             )+
             _ => {
-                return Err($crate::parser::error::unexpected_token(
+                return Err(Box::new($crate::parser::error::unexpected_token(
                     vec![$($message.into(),)+],
                     token,
-                ))
+                )))
             }
         }
     }};
@@ -98,7 +98,7 @@ macro_rules! expect_literal {
 #[macro_export]
 macro_rules! expected_token_err {
     ([ $($expected:literal),+ $(,)? ], $state:expr $(,)?) => {{
-        Err($crate::expected_token!([$($expected),+], $state))
+        Err(Box::new($crate::expected_token!([$($expected),+], $state)))
     }};
 
     ($expected:literal, $state:expr $(,)?) => {
@@ -128,7 +128,7 @@ macro_rules! expected_scope {
                 $( $pattern )|+ $( if $guard )? => $out,
             )+
             _ => {
-                return Err($crate::parser::error::reached_unpredictable_state($state.stream.current().span));
+                return Err(Box::new($crate::parser::error::reached_unpredictable_state($state.stream.current().span)));
             }
         }
     }};
@@ -1,5 +1,6 @@
 use crate::expect_literal;
 use crate::lexer::token::OpenTagKind;
+use crate::lexer::token::Span;
 use crate::lexer::token::Token;
 use crate::lexer::token::TokenKind;
 use crate::lexer::Lexer;
@@ -9,6 +10,7 @@ use crate::parser::ast::declares::DeclareEntryGroup;
 use crate::parser::ast::declares::DeclareStatement;
 use crate::parser::ast::variables::Variable;
 use crate::parser::ast::{Program, Statement, StaticVar};
+use crate::parser::config::ParserConfig;
 use crate::parser::error::ParseErrorStack;
 use crate::parser::error::ParseResult;
 use crate::parser::internal::attributes;
@@ -31,6 +33,8 @@ use crate::parser::internal::variables;
 use crate::parser::state::State;
 
 pub use crate::lexer::stream::TokenStream;
+pub use crate::parser::internal::precedences::Associativity;
+pub use crate::parser::internal::precedences::Precedence;
 
 use self::ast::ClosingTagStatement;
 use self::ast::EchoOpeningTagStatement;
@@ -43,10 +47,11 @@ use self::ast::InlineHtmlStatement;
 use self::ast::ReturnStatement;
 use self::ast::ShortOpeningTagStatement;
 use self::ast::StaticStatement;
-use self::internal::precedences::Precedence;
 
 pub mod ast;
+pub mod config;
 pub mod error;
+pub mod validation;
 
 mod expressions;
 mod internal;
@@ -54,6 +59,13 @@ mod macros;
 mod state;
 
 pub fn parse<B: ?Sized + AsRef<[u8]>>(input: &B) -> Result<Program, ParseErrorStack> {
+    parse_with_config(input, ParserConfig::default())
+}
+
+pub fn parse_with_config<B: ?Sized + AsRef<[u8]>>(
+    input: &B,
+    config: ParserConfig,
+) -> Result<Program, ParseErrorStack> {
     let lexer = Lexer::new();
     let tokens = match lexer.tokenize(input) {
         Ok(tokens) => tokens,
@@ -65,21 +77,35 @@ pub fn parse<B: ?Sized + AsRef<[u8]>>(input: &B) -> Result<Program, ParseErrorSt
         }
     };
 
-    construct(&tokens)
+    construct_with_config(&tokens, config)
 }
 
 pub fn construct(tokens: &[Token]) -> Result<Program, ParseErrorStack> {
+    construct_with_config(tokens, ParserConfig::default())
+}
+
+pub fn construct_with_config(
+    tokens: &[Token],
+    config: ParserConfig,
+) -> Result<Program, ParseErrorStack> {
     let mut stream = TokenStream::new(tokens);
-    let mut state = State::new(&mut stream);
+    let mut state = State::new(&mut stream, config);
 
     let mut program = Program::new();
 
     while !state.stream.is_eof() {
-        let statement = match top_level_statement(&mut state) {
+        blocks::comments(&mut state, &mut program);
+
+        let start = state.stream.current().span;
+        let statement = match recoverable(&mut state, top_level_statement) {
             Ok(statement) => statement,
             Err(error) => {
+                // keep the partial program walkable, marking where the malformed
+                // statement begins.
+                program.push(Statement::Error(start));
+
                 let mut previous = state.errors;
-                previous.push(error);
+                previous.push(*error);
 
                 return Err(ParseErrorStack {
                     errors: previous,
@@ -91,6 +117,12 @@ pub fn construct(tokens: &[Token]) -> Result<Program, ParseErrorStack> {
         program.push(statement);
     }
 
+    blocks::trailing_comments(&mut state, &mut program);
+
+    if state.config.validate_gotos {
+        state.errors.extend(validation::validate_gotos(&program));
+    }
+
     let errors = state.errors;
     if !errors.is_empty() {
         return Err(ParseErrorStack {
@@ -99,7 +131,81 @@ pub fn construct(tokens: &[Token]) -> Result<Program, ParseErrorStack> {
         });
     }
 
-    Ok(program.to_vec())
+    Ok(program)
+}
+
+/// Parse a statement using the given parser, recovering from an error by recording it,
+/// then skipping to the end of the malformed statement, which becomes a [`Statement::Error`].
+///
+/// Errors at the end of the file cannot be recovered from, and are returned instead.
+fn recoverable(
+    state: &mut State,
+    parser: fn(&mut State) -> ParseResult<Statement>,
+) -> ParseResult<Statement> {
+    let start = state.stream.current().span;
+    let depth = state.stack.len();
+
+    let error = match parser(state) {
+        Ok(statement) => return Ok(statement),
+        Err(error) => error,
+    };
+
+    if state.stream.is_eof() {
+        return Err(error);
+    }
+
+    state.record(*error);
+
+    // the malformed statement may have left its scope entered, or its attributes pending.
+    state.stack.truncate(depth);
+    state.get_attributes();
+
+    synchronize(state);
+
+    Ok(Statement::Error(start))
+}
+
+/// Skip to the end of the current statement, which is either the next `;` or the end
+/// of a block, as long as the block was opened within the statement.
+fn synchronize(state: &mut State) {
+    let mut depth = 0;
+    let mut skipped = false;
+
+    loop {
+        match state.stream.current().kind {
+            TokenKind::Eof => return,
+            // a closing brace or tag belongs to the enclosing block, unless nothing
+            // else has been skipped, in which case the statement would never end.
+            TokenKind::RightBrace | TokenKind::CloseTag if depth == 0 => {
+                if !skipped {
+                    state.stream.next();
+                }
+
+                return;
+            }
+            TokenKind::SemiColon if depth == 0 => {
+                state.stream.next();
+
+                return;
+            }
+            TokenKind::LeftBrace | TokenKind::CurlyOpen | TokenKind::DollarLeftBrace => {
+                depth += 1;
+            }
+            TokenKind::RightBrace => {
+                depth -= 1;
+
+                if depth == 0 {
+                    state.stream.next();
+
+                    return;
+                }
+            }
+            _ => {}
+        }
+
+        state.stream.next();
+        skipped = true;
+    }
 }
 
 fn top_level_statement(state: &mut State) -> ParseResult<Statement> {
@@ -107,9 +213,22 @@ fn top_level_statement(state: &mut State) -> ParseResult<Statement> {
         TokenKind::Namespace => namespaces::namespace(state)?,
         TokenKind::Use => uses::use_statement(state)?,
         TokenKind::Const => Statement::Constant(constants::parse(state)?),
+        // nothing can start with a closing delimiter, so report it here rather
+        // than letting the statement parser fail somewhere less obvious.
+        TokenKind::RightBrace | TokenKind::RightParen | TokenKind::RightBracket => {
+            return Err(Box::new(error::unexpected_closing_delimiter(
+                state.stream.current(),
+            )));
+        }
         TokenKind::HaltCompiler => {
+            let halt_compiler = state.stream.current().span;
             state.stream.next();
 
+            let left_parenthesis = utils::skip_left_parenthesis(state)?;
+            let right_parenthesis = utils::skip_right_parenthesis(state)?;
+            let ending = utils::skip_ending(state)?;
+            let offset = state.stream.current().span.position;
+
             let content = if let TokenKind::InlineHtml = state.stream.current().kind.clone() {
                 let content = state.stream.current().value.clone();
                 state.stream.next();
@@ -118,7 +237,14 @@ fn top_level_statement(state: &mut State) -> ParseResult<Statement> {
                 None
             };
 
-            Statement::HaltCompiler(HaltCompilerStatement { content })
+            Statement::HaltCompiler(HaltCompilerStatement {
+                halt_compiler,
+                left_parenthesis,
+                right_parenthesis,
+                ending,
+                offset,
+                content,
+            })
         }
         _ => statement(state)?,
     };
@@ -147,24 +273,8 @@ fn statement(state: &mut State) -> ParseResult<Statement> {
             {
                 enums::parse(state)?
             }
-            TokenKind::Function
-                if identifiers::is_identifier_maybe_soft_reserved(&peek.kind)
-                    || peek.kind == TokenKind::Ampersand =>
-            {
-                if peek.kind == TokenKind::Ampersand {
-                    if !identifiers::is_identifier_maybe_soft_reserved(
-                        &state.stream.lookahead(1).kind,
-                    ) {
-                        return Ok(Statement::Expression(ExpressionStatement {
-                            expression: expressions::attributes(state, &Precedence::Lowest)?,
-                            ending: utils::skip_ending(state)?,
-                        }));
-                    }
-
-                    functions::function(state)?
-                } else {
-                    functions::function(state)?
-                }
+            TokenKind::Function if functions::is_function_declaration(state) => {
+                functions::function(state)?
             }
             _ => Statement::Expression(ExpressionStatement {
                 expression: expressions::attributes(state, &Precedence::Lowest)?,
@@ -179,6 +289,11 @@ fn statement(state: &mut State) -> ParseResult<Statement> {
 
                 Statement::EchoOpeningTag(EchoOpeningTagStatement { span })
             }
+            // `<?= $a, $b ?>` is short for `<?php echo $a, $b ?>`, so the opening tag
+            // is followed by an echo statement starting at the tag.
+            _ if state.stream.previous().kind == TokenKind::OpenTag(OpenTagKind::Echo) => {
+                echo(state, state.stream.previous().span)?
+            }
             TokenKind::OpenTag(OpenTagKind::Full) => {
                 let span = current.span;
                 state.stream.next();
@@ -211,24 +326,8 @@ fn statement(state: &mut State) -> ParseResult<Statement> {
             {
                 enums::parse(state)?
             }
-            TokenKind::Function
-                if identifiers::is_identifier_maybe_soft_reserved(&peek.kind)
-                    || peek.kind == TokenKind::Ampersand =>
-            {
-                if peek.kind == TokenKind::Ampersand {
-                    if !identifiers::is_identifier_maybe_soft_reserved(
-                        &state.stream.lookahead(1).kind,
-                    ) {
-                        return Ok(Statement::Expression(ExpressionStatement {
-                            expression: expressions::attributes(state, &Precedence::Lowest)?,
-                            ending: utils::skip_ending(state)?,
-                        }));
-                    }
-
-                    functions::function(state)?
-                } else {
-                    functions::function(state)?
-                }
+            TokenKind::Function if functions::is_function_declaration(state) => {
+                functions::function(state)?
             }
             TokenKind::Goto => goto::goto_statement(state)?,
             token
@@ -270,11 +369,9 @@ fn statement(state: &mut State) -> ParseResult<Statement> {
                 };
 
                 let body = match state.stream.current().kind.clone() {
-                    TokenKind::SemiColon => {
-                        let span = utils::skip_semicolon(state)?;
-
-                        DeclareBody::Noop { semicolon: span }
-                    }
+                    TokenKind::SemiColon | TokenKind::CloseTag => DeclareBody::Noop {
+                        ending: utils::skip_ending(state)?,
+                    },
                     TokenKind::LeftBrace => {
                         let start = utils::skip_left_brace(state)?;
                         let statements =
@@ -293,7 +390,7 @@ fn statement(state: &mut State) -> ParseResult<Statement> {
                             blocks::multiple_statements_until(state, &TokenKind::EndDeclare)?;
                         let end = (
                             utils::skip(state, TokenKind::EndDeclare)?,
-                            utils::skip_semicolon(state)?,
+                            utils::skip_ending(state)?,
                         );
 
                         DeclareBody::Block {
@@ -304,12 +401,9 @@ fn statement(state: &mut State) -> ParseResult<Statement> {
                     }
                     _ => {
                         let expression = expressions::create(state)?;
-                        let end = utils::skip_semicolon(state)?;
+                        let ending = utils::skip_ending(state)?;
 
-                        DeclareBody::Expression {
-                            expression,
-                            semicolon: end,
-                        }
+                        DeclareBody::Expression { expression, ending }
                     }
                 };
 
@@ -335,7 +429,7 @@ fn statement(state: &mut State) -> ParseResult<Statement> {
                     }
                 }
 
-                utils::skip_semicolon(state)?;
+                utils::skip_ending(state)?;
                 Statement::Global(GlobalStatement {
                     global: span,
                     variables,
@@ -369,7 +463,7 @@ fn statement(state: &mut State) -> ParseResult<Statement> {
                     }
                 }
 
-                utils::skip_semicolon(state)?;
+                utils::skip_ending(state)?;
 
                 Statement::Static(StaticStatement { vars })
             }
@@ -399,22 +493,7 @@ fn statement(state: &mut State) -> ParseResult<Statement> {
             TokenKind::Echo => {
                 state.stream.next();
 
-                let mut values = Vec::new();
-                loop {
-                    values.push(expressions::create(state)?);
-
-                    if state.stream.current().kind == TokenKind::Comma {
-                        state.stream.next();
-                    } else {
-                        break;
-                    }
-                }
-
-                Statement::Echo(EchoStatement {
-                    echo: current.span,
-                    values,
-                    ending: utils::skip_ending(state)?,
-                })
+                echo(state, current.span)?
             }
             TokenKind::Return => {
                 state.stream.next();
@@ -443,3 +522,72 @@ fn statement(state: &mut State) -> ParseResult<Statement> {
 
     Ok(statement)
 }
+
+fn echo(state: &mut State, echo: Span) -> ParseResult<Statement> {
+    let mut values = Vec::new();
+    loop {
+        values.push(expressions::create(state)?);
+
+        if state.stream.current().kind == TokenKind::Comma {
+            state.stream.next();
+        } else {
+            break;
+        }
+    }
+
+    Ok(Statement::Echo(EchoStatement {
+        echo,
+        values,
+        ending: utils::skip_ending(state)?,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_partial_program_ends_with_error_statement() {
+        let code = "<?php\n\necho 1 ? 2 : 3 ? 4 : 5;\n\nif ($a {\n}\n";
+        let stack = crate::parse(code).unwrap_err();
+
+        let ids: Vec<&str> = stack.errors.iter().map(|error| error.id.as_str()).collect();
+        assert_eq!(ids, vec!["E071", "E005"]);
+
+        assert_eq!(stack.partial.len(), 3);
+        assert!(matches!(stack.partial[0], Statement::FullOpeningTag(_)));
+        assert!(matches!(stack.partial[1], Statement::Echo(_)));
+
+        match &stack.partial[2] {
+            Statement::Error(span) => {
+                assert_eq!(span.line, 5);
+                assert_eq!(&code.as_bytes()[span.position..span.position + 2], b"if");
+            }
+            statement => panic!("expected an error statement, found {:?}", statement),
+        }
+    }
+
+    #[test]
+    fn test_recover_after_malformed_statement() {
+        let code = "<?php\nfunction f() {\n    $a = ;\n    echo 1;\n}\nfoo(;\necho 2;\n";
+        let stack = crate::parse(code).unwrap_err();
+
+        let ids: Vec<&str> = stack.errors.iter().map(|error| error.id.as_str()).collect();
+        assert_eq!(ids, vec!["E003", "E003"]);
+
+        assert_eq!(stack.partial.len(), 4);
+        assert!(matches!(stack.partial[2], Statement::Error(span) if span.line == 6));
+        assert!(matches!(stack.partial[3], Statement::Echo(_)));
+
+        match &stack.partial[1] {
+            Statement::Function(function) => {
+                let statements = &function.body.statements;
+
+                assert_eq!(statements.len(), 2);
+                assert!(matches!(statements[0], Statement::Error(span) if span.line == 3));
+                assert!(matches!(statements[1], Statement::Echo(_)));
+            }
+            statement => panic!("expected a function statement, found {:?}", statement),
+        }
+    }
+}
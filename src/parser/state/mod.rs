@@ -1,10 +1,16 @@
 use std::collections::VecDeque;
 use std::fmt::Display;
 
+use crate::lexer::stream::Checkpoint as StreamCheckpoint;
 use crate::lexer::stream::TokenStream;
+use crate::lexer::token::Span;
 use crate::parser::ast::attributes::AttributeGroup;
 use crate::parser::ast::identifiers::SimpleIdentifier;
+use crate::parser::config::ParserConfig;
+use crate::parser::config::PhpVersion;
+use crate::parser::error;
 use crate::parser::error::ParseError;
+use crate::parser::error::ParseResult;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub enum NamespaceType {
@@ -16,6 +22,22 @@ pub enum NamespaceType {
 pub enum Scope {
     Namespace(SimpleIdentifier),
     BracedNamespace(Option<SimpleIdentifier>),
+    Function,
+    Method,
+    StaticMethod,
+    AnonymousFunction,
+    Loop,
+    Switch,
+}
+
+/// A saved parser state, used to backtrack after a speculative parse.
+///
+/// See [`State::checkpoint`] and [`State::restore`].
+#[derive(Debug, Clone)]
+pub struct Checkpoint {
+    stream: StreamCheckpoint,
+    attributes: usize,
+    errors: usize,
 }
 
 #[derive(Debug)]
@@ -25,16 +47,21 @@ pub struct State<'a> {
     pub attributes: Vec<AttributeGroup>,
     pub namespace_type: Option<NamespaceType>,
     pub errors: Vec<ParseError>,
+    pub config: ParserConfig,
+    /// Whether the parser is within a parameter default value or an attribute argument.
+    pub initializer: bool,
 }
 
 impl<'a> State<'a> {
-    pub fn new(tokens: &'a mut TokenStream<'a>) -> Self {
+    pub fn new(tokens: &'a mut TokenStream<'a>, config: ParserConfig) -> Self {
         Self {
             stack: VecDeque::with_capacity(32),
             stream: tokens,
+            config,
             namespace_type: None,
             attributes: vec![],
             errors: vec![],
+            initializer: false,
         }
     }
 
@@ -50,10 +77,61 @@ impl<'a> State<'a> {
         attributes
     }
 
+    /// Run the given parser within an initializer, such as a parameter default value
+    /// or an attribute argument, where `new` is only allowed from PHP 8.1.
+    pub fn initializer<T>(
+        &mut self,
+        parser: impl FnOnce(&mut Self) -> ParseResult<T>,
+    ) -> ParseResult<T> {
+        let previous = std::mem::replace(&mut self.initializer, true);
+        let result = parser(self);
+        self.initializer = previous;
+
+        result
+    }
+
     pub fn record(&mut self, error: ParseError) {
         self.errors.push(error);
     }
 
+    /// Record an error if the targeted PHP version predates the version
+    /// in which the given feature was introduced.
+    pub fn require(&mut self, version: PhpVersion, feature: &str, span: Span, length: usize) {
+        if self.config.php_version < version {
+            self.record(error::feature_not_available(
+                feature,
+                version,
+                self.config.php_version,
+                span,
+                length,
+            ));
+        }
+    }
+
+    /// Save the current position of the parser.
+    ///
+    /// This allows speculatively parsing ahead, then rewinding
+    /// using [`State::restore`] if the speculation did not pan out.
+    ///
+    /// Pending attributes are not saved, so the speculative parse
+    /// must not take them using [`State::get_attributes`].
+    pub fn checkpoint(&self) -> Checkpoint {
+        Checkpoint {
+            stream: self.stream.checkpoint(),
+            attributes: self.attributes.len(),
+            errors: self.errors.len(),
+        }
+    }
+
+    /// Rewind the parser to a previously saved position.
+    ///
+    /// Any attributes and errors recorded since the checkpoint was created are discarded.
+    pub fn restore(&mut self, checkpoint: Checkpoint) {
+        self.stream.restore(checkpoint.stream);
+        self.attributes.truncate(checkpoint.attributes);
+        self.errors.truncate(checkpoint.errors);
+    }
+
     /// Return the namespace type used in the current state
     ///
     /// The namespace type is retrieve from the last entered
@@ -69,6 +147,46 @@ impl<'a> State<'a> {
         self.stack.iter().next()
     }
 
+    /// Determine whether the parser is within the body of a function, method,
+    /// closure or arrow function.
+    pub fn in_function(&self) -> bool {
+        self.function().is_some()
+    }
+
+    /// Determine whether `$this` can refer to an object at the current position.
+    ///
+    /// This is only known not to be the case within free functions and static
+    /// methods, as closures can be bound to an object, and files can be
+    /// included from within a method.
+    pub fn in_object_context(&self) -> bool {
+        !matches!(self.function(), Some(Scope::Function | Scope::StaticMethod))
+    }
+
+    /// Count the loops and switch statements enclosing the current position,
+    /// within the innermost function.
+    pub fn loop_depth(&self) -> usize {
+        self.stack
+            .iter()
+            .rev()
+            .take_while(|scope| !Self::is_function(scope))
+            .filter(|scope| matches!(scope, Scope::Loop | Scope::Switch))
+            .count()
+    }
+
+    fn function(&self) -> Option<&Scope> {
+        self.stack
+            .iter()
+            .rev()
+            .find(|scope| Self::is_function(scope))
+    }
+
+    fn is_function(scope: &Scope) -> bool {
+        matches!(
+            scope,
+            Scope::Function | Scope::Method | Scope::StaticMethod | Scope::AnonymousFunction
+        )
+    }
+
     pub fn named<T: Display + ?Sized>(&self, name: &T) -> String {
         match self.namespace() {
             Some(Scope::Namespace(n)) | Some(Scope::BracedNamespace(Some(n))) => {
@@ -86,6 +204,12 @@ impl<'a> State<'a> {
             Scope::BracedNamespace(_) => {
                 self.namespace_type = Some(NamespaceType::Braced);
             }
+            Scope::Function
+            | Scope::Method
+            | Scope::StaticMethod
+            | Scope::AnonymousFunction
+            | Scope::Loop
+            | Scope::Switch => {}
         }
 
         self.stack.push_back(scope);
@@ -95,3 +219,43 @@ impl<'a> State<'a> {
         self.stack.pop_back();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lexer::token::TokenKind;
+    use crate::lexer::Lexer;
+    use crate::parser::expressions;
+    use crate::parser::internal::attributes;
+
+    #[test]
+    fn test_restore_after_failed_speculative_parse() {
+        let tokens = Lexer::new()
+            .tokenize("<?php // leading\n#[Foo] $a = ;")
+            .unwrap();
+        let mut stream = TokenStream::new(&tokens);
+        let mut state = State::new(&mut stream, ParserConfig::default());
+
+        state.stream.next();
+
+        let start = state.stream.current().span;
+        let checkpoint = state.checkpoint();
+
+        assert!(attributes::gather_attributes(&mut state).unwrap());
+        assert_eq!(state.stream.comments().comments.len(), 1);
+
+        let error = expressions::create(&mut state).unwrap_err();
+        state.record(*error);
+
+        assert_eq!(state.attributes.len(), 1);
+        assert_eq!(state.errors.len(), 1);
+
+        state.restore(checkpoint);
+
+        assert_eq!(state.stream.current().kind, TokenKind::Attribute);
+        assert_eq!(state.stream.current().span, start);
+        assert!(state.attributes.is_empty());
+        assert!(state.errors.is_empty());
+        assert_eq!(state.stream.comments().comments.len(), 1);
+    }
+}
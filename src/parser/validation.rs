@@ -0,0 +1,97 @@
+use std::collections::HashMap;
+
+use crate::downcast::downcast;
+use crate::lexer::token::Span;
+use crate::node::Node;
+use crate::parser::ast::functions::FunctionBody;
+use crate::parser::ast::functions::MethodBody;
+use crate::parser::ast::goto::GotoStatement;
+use crate::parser::ast::goto::LabelStatement;
+use crate::parser::ast::Program;
+use crate::parser::error;
+use crate::parser::error::ParseError;
+
+/// Check that every `goto` targets a label defined within the same function,
+/// and that no label is defined more than once within a function.
+///
+/// Labels are scoped to the enclosing function, method, or closure body,
+/// while code outside of any function shares a single top-level scope.
+pub fn validate_gotos(program: &Program) -> Vec<ParseError> {
+    let mut validator = GotoValidator {
+        scopes: vec![LabelScope::default()],
+        errors: vec![],
+    };
+
+    for statement in program {
+        validator.visit_node(statement);
+    }
+
+    validator.exit();
+    validator.errors.sort_by_key(|error| error.span.position);
+    validator.errors
+}
+
+#[derive(Default)]
+struct LabelScope {
+    labels: HashMap<Vec<u8>, Span>,
+    gotos: Vec<(Vec<u8>, Span)>,
+}
+
+struct GotoValidator {
+    scopes: Vec<LabelScope>,
+    errors: Vec<ParseError>,
+}
+
+impl GotoValidator {
+    fn exit(&mut self) {
+        let scope = self.scopes.pop().unwrap();
+
+        for (label, span) in scope.gotos {
+            if !scope.labels.contains_key(&label) {
+                self.errors
+                    .push(error::goto_to_undefined_label(&label, span));
+            }
+        }
+    }
+
+    fn visit_node(&mut self, node: &dyn Node) {
+        let is_function_body =
+            downcast::<FunctionBody>(node).is_some() || downcast::<MethodBody>(node).is_some();
+
+        if is_function_body {
+            self.scopes.push(LabelScope::default());
+        }
+
+        self.visit(node);
+
+        for child in node.children_ref() {
+            self.visit_node(child);
+        }
+
+        if is_function_body {
+            self.exit();
+        }
+    }
+
+    fn visit(&mut self, node: &dyn Node) {
+        let scope = self.scopes.last_mut().unwrap();
+
+        if let Some(statement) = downcast::<LabelStatement>(node) {
+            let label = &statement.label;
+
+            if let Some(first) = scope.labels.get(&label.value.bytes) {
+                self.errors.push(error::label_already_defined(
+                    &label.value,
+                    *first,
+                    label.span,
+                ));
+            } else {
+                scope.labels.insert(label.value.bytes.clone(), label.span);
+            }
+        } else if let Some(statement) = downcast::<GotoStatement>(node) {
+            scope
+                .gotos
+                .push((statement.label.value.bytes.clone(), statement.label.span));
+        }
+    }
+}
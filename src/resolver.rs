@@ -0,0 +1,254 @@
+use std::collections::HashMap;
+
+use crate::lexer::byte_string::ByteString;
+use crate::parser::ast::identifiers::Name;
+use crate::parser::ast::GroupUseStatement;
+use crate::parser::ast::Statement;
+use crate::parser::ast::Use;
+use crate::parser::ast::UseKind;
+use crate::parser::ast::UseStatement;
+
+/// Resolves relative names to their fully qualified form, using the current
+/// namespace and the `use` imports in scope.
+///
+/// The resolver is fed statements in source order: call [`NameResolver::enter_namespace`]
+/// whenever a namespace is entered, and [`NameResolver::import`] for every statement,
+/// then use [`NameResolver::resolve`] to resolve names found along the way.
+#[derive(Debug, Default, Clone)]
+pub struct NameResolver {
+    namespace: Option<Name>,
+    classes: HashMap<Vec<u8>, Name>,
+    functions: HashMap<Vec<u8>, Name>,
+    constants: HashMap<Vec<u8>, Name>,
+}
+
+impl NameResolver {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Enter the given namespace, or the global namespace if `None`.
+    ///
+    /// Imports are scoped to a namespace, so any previous imports are forgotten.
+    pub fn enter_namespace(&mut self, name: Option<&Name>) {
+        self.namespace = name.cloned();
+        self.classes.clear();
+        self.functions.clear();
+        self.constants.clear();
+    }
+
+    /// Register the imports of a `use` statement. Any other statement is ignored.
+    pub fn import(&mut self, statement: &Statement) {
+        match statement {
+            Statement::Use(UseStatement { kind, uses }) => {
+                for r#use in uses {
+                    self.add(None, kind, r#use);
+                }
+            }
+            Statement::GroupUse(GroupUseStatement { prefix, kind, uses }) => {
+                for r#use in uses {
+                    self.add(Some(prefix), kind, r#use);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Resolve a name to its fully qualified form, keeping the span of the given name.
+    ///
+    /// `kind` determines which imports are consulted for an unqualified name:
+    /// `UseKind::Normal` for class-like names, `UseKind::Function` for function
+    /// calls, and `UseKind::Const` for constant fetches. Qualified names are always
+    /// resolved using class imports, as PHP does, while namespace relative names
+    /// such as `namespace\Foo` ignore imports altogether.
+    ///
+    /// Unqualified function and constant names that are not imported resolve to
+    /// the current namespace, although PHP falls back to the global namespace at
+    /// runtime when no such function or constant exists.
+    pub fn resolve(&self, name: &Name, kind: &UseKind) -> Name {
+        if name.fully_qualified {
+            return name.clone();
+        }
+
+        if name.is_namespace_relative() {
+            return self.qualify(name, &name.segments[1..]);
+        }
+
+        if name.is_unqualified() {
+            let imported = match kind {
+                UseKind::Normal => {
+                    if is_special_class_name(name.first()) {
+                        return name.clone();
+                    }
+
+                    self.classes.get(&name.first().to_ascii_lowercase())
+                }
+                UseKind::Function => self.functions.get(&name.first().to_ascii_lowercase()),
+                UseKind::Const => self.constants.get(&name.first().bytes),
+            };
+
+            if let Some(imported) = imported {
+                return Name {
+                    span: name.span,
+                    ..imported.clone()
+                };
+            }
+        } else if let Some(imported) = self.classes.get(&name.first().to_ascii_lowercase()) {
+            let mut segments = imported.segments.clone();
+            segments.extend(name.segments[1..].iter().cloned());
+
+            return Name {
+                span: name.span,
+                segments,
+                fully_qualified: true,
+            };
+        }
+
+        self.qualify(name, &name.segments)
+    }
+
+    /// Prefix the given segments with the current namespace, keeping the span of `name`.
+    fn qualify(&self, name: &Name, relative: &[ByteString]) -> Name {
+        let mut segments = self
+            .namespace
+            .as_ref()
+            .map(|namespace| namespace.segments.clone())
+            .unwrap_or_default();
+        segments.extend(relative.iter().cloned());
+
+        Name {
+            span: name.span,
+            segments,
+            fully_qualified: true,
+        }
+    }
+
+    fn add(&mut self, prefix: Option<&Name>, kind: &UseKind, r#use: &Use) {
+        let mut name = r#use.name.clone();
+        if let Some(prefix) = prefix {
+            name.segments.splice(0..0, prefix.segments.iter().cloned());
+        }
+
+        // names in `use` statements are always fully qualified.
+        name.fully_qualified = true;
+
+        let alias = match &r#use.alias {
+            Some(alias) => alias.value.clone(),
+            None => name.last().clone(),
+        };
+
+        match r#use.kind.as_ref().unwrap_or(kind) {
+            UseKind::Normal => self.classes.insert(alias.to_ascii_lowercase(), name),
+            UseKind::Function => self.functions.insert(alias.to_ascii_lowercase(), name),
+            UseKind::Const => self.constants.insert(alias.bytes, name),
+        };
+    }
+}
+
+fn is_special_class_name(name: &ByteString) -> bool {
+    matches!(
+        &name.to_ascii_lowercase()[..],
+        b"self" | b"static" | b"parent"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lexer::token::Span;
+    use crate::parser::ast::namespaces::NamespaceStatement;
+
+    fn resolver(code: &str) -> NameResolver {
+        let program = crate::parse(code).unwrap();
+        let mut resolver = NameResolver::new();
+
+        for statement in &program {
+            match statement {
+                Statement::Namespace(NamespaceStatement::Unbraced(namespace)) => {
+                    resolver.enter_namespace(Some(&namespace.name));
+
+                    for statement in &namespace.statements {
+                        resolver.import(statement);
+                    }
+                }
+                statement => resolver.import(statement),
+            }
+        }
+
+        resolver
+    }
+
+    fn resolve(resolver: &NameResolver, name: &str, kind: UseKind) -> String {
+        resolver
+            .resolve(&Name::new(Span::new(1, 1, 0), name.as_bytes()), &kind)
+            .to_string()
+    }
+
+    #[test]
+    fn test_resolve_aliased_imports() {
+        let resolver = resolver(
+            r"<?php
+            namespace App;
+            use Foo\Bar;
+            use Foo\Baz as Qux;
+            use function Foo\helper as help;
+            use const Foo\VERSION;
+            ",
+        );
+
+        assert_eq!(resolve(&resolver, "Bar", UseKind::Normal), r"\Foo\Bar");
+        assert_eq!(resolve(&resolver, "bar", UseKind::Normal), r"\Foo\Bar");
+        assert_eq!(resolve(&resolver, "Qux", UseKind::Normal), r"\Foo\Baz");
+        assert_eq!(
+            resolve(&resolver, r"Qux\Child", UseKind::Normal),
+            r"\Foo\Baz\Child"
+        );
+        assert_eq!(
+            resolve(&resolver, "help", UseKind::Function),
+            r"\Foo\helper"
+        );
+        assert_eq!(
+            resolve(&resolver, "VERSION", UseKind::Const),
+            r"\Foo\VERSION"
+        );
+
+        assert_eq!(resolve(&resolver, "Other", UseKind::Normal), r"\App\Other");
+        assert_eq!(resolve(&resolver, "Bar", UseKind::Function), r"\App\Bar");
+        assert_eq!(resolve(&resolver, r"\Bar", UseKind::Normal), r"\Bar");
+        assert_eq!(resolve(&resolver, "self", UseKind::Normal), "self");
+        assert_eq!(
+            resolve(&resolver, r"namespace\Bar", UseKind::Normal),
+            r"\App\Bar"
+        );
+    }
+
+    #[test]
+    fn test_resolve_grouped_imports() {
+        let resolver = resolver(
+            r"<?php
+            namespace App;
+            use Foo\{Bar, Baz as Qux, function helper, const VERSION};
+            use function Util\{first, last as final_item};
+            ",
+        );
+
+        assert_eq!(resolve(&resolver, "Bar", UseKind::Normal), r"\Foo\Bar");
+        assert_eq!(resolve(&resolver, "Qux", UseKind::Normal), r"\Foo\Baz");
+        assert_eq!(
+            resolve(&resolver, "helper", UseKind::Function),
+            r"\Foo\helper"
+        );
+        assert_eq!(
+            resolve(&resolver, "VERSION", UseKind::Const),
+            r"\Foo\VERSION"
+        );
+        assert_eq!(
+            resolve(&resolver, "first", UseKind::Function),
+            r"\Util\first"
+        );
+        assert_eq!(
+            resolve(&resolver, "final_item", UseKind::Function),
+            r"\Util\last"
+        );
+    }
+}
@@ -0,0 +1,16 @@
+use wasm_bindgen::prelude::wasm_bindgen;
+
+/// Parse the given source and serialize the resulting ast to JSON.
+///
+/// This is the entrypoint used when running the parser in the browser, so
+/// it avoids touching the filesystem or spawning threads. Parse errors are
+/// returned as a plain-text report.
+#[wasm_bindgen]
+pub fn parse_to_json(source: &str) -> Result<String, String> {
+    match crate::parse(source) {
+        Ok(ast) => serde_json::to_string(&ast).map_err(|error| error.to_string()),
+        Err(error) => Err(error
+            .report(source, None, false, true)
+            .unwrap_or_else(|_| error.to_string())),
+    }
+}
@@ -1 +1 @@
-<?php break;
\ No newline at end of file
+<?php while (true) { break; }
\ No newline at end of file
@@ -1 +1 @@
-<?php break 2;
\ No newline at end of file
+<?php while (true) { while (true) { break 2; } }
\ No newline at end of file
@@ -1 +1 @@
-<?php yield;
\ No newline at end of file
+<?php function foo() { yield; }
\ No newline at end of file
@@ -1 +1 @@
-<?php yield 1;
\ No newline at end of file
+<?php function foo() { yield 1; }
\ No newline at end of file
@@ -1 +1 @@
-<?php yield from 1;
\ No newline at end of file
+<?php function foo() { yield from 1; }
\ No newline at end of file
@@ -0,0 +1,6 @@
+<?php
+
+#[Pure]
+function makeAdder(int $n): callable {
+    return fn($x) => $x + $n;
+}
@@ -0,0 +1,3 @@
+<?php
+
+eval "return 1;";
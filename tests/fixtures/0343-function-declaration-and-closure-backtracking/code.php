@@ -0,0 +1,7 @@
+<?php
+
+function /* by-ref */ &foo() {}
+
+function /* by-ref */ &() {};
+
+function /* closure */ () {};
@@ -0,0 +1,3 @@
+<?php
+
+$output = `ls -la $dirs[0] {$this->path} ${suffix} $file->name`;
@@ -0,0 +1,3 @@
+<?php
+
+$output = `ls -la $dir;
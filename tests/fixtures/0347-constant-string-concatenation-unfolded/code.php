@@ -0,0 +1,4 @@
+<?php
+
+$a = 'a' . 'b' . "c";
+$b = 'a' . $x . 'b' . 'c';
@@ -0,0 +1,7 @@
+<?php
+
+try {
+    foo();
+} catch (Exception | TypeError
+    | typeerror $e) {
+}
@@ -0,0 +1,6 @@
+<?php
+
+try {
+    foo();
+} catch (\TypeError | Foo\BarException | Exception $e) {
+}
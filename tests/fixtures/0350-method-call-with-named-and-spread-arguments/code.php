@@ -0,0 +1,5 @@
+<?php
+
+$obj->method(1, ...$rest, name: $v);
+$obj?->method(...$rest, name: $v);
+Foo::method(1, ...$args, name: $v);
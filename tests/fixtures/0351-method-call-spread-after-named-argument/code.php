@@ -0,0 +1,3 @@
+<?php
+
+$obj->method(1, name: $v, ...$rest);
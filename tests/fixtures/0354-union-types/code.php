@@ -0,0 +1,11 @@
+<?php
+
+class Foo {
+    public int|string $id;
+
+    public function bar(A|B $value): A|B|null {
+        return $value;
+    }
+}
+
+function f(): A|B {}
@@ -0,0 +1,8 @@
+<?php
+
+$map = [
+    Status::Active->value => 'x',
+    Color::RED => 'y',
+    self::FOO => 'z',
+    Suit::from('H')?->name => 'w',
+];
@@ -0,0 +1,9 @@
+<?php
+
+class Foo {
+    public Countable&Traversable $items;
+
+    public function bar(A&B $a, A&B &$b, A&B ...$c): Countable&Traversable {
+        return $a;
+    }
+}
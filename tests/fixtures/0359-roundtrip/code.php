@@ -0,0 +1,16 @@
+<?php
+
+namespace App;
+
+use Foo\Bar;
+
+final class Baz extends Bar implements \Countable
+{
+    public function count(): int
+    {
+        return \count($this->items) + 1;
+    }
+}
+
+$baz = new Baz();
+echo $baz->count();
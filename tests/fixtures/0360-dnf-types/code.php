@@ -0,0 +1,9 @@
+<?php
+
+class Foo {
+    public (A&B)|null $bar;
+
+    public function baz((A&B)|C $c): (A&B)|(C&D)|null {
+        return $c;
+    }
+}
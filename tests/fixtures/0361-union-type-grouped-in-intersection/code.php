@@ -0,0 +1,5 @@
+<?php
+
+function foo((A|B)&C $c) {
+    return $c;
+}
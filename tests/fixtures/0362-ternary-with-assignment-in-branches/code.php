@@ -0,0 +1,5 @@
+<?php
+
+$x = $cond ? $a = 1 : $b = 2;
+
+$cond ? $a = 1 : $b = 2;
@@ -0,0 +1,13 @@
+<?php
+
+function f(?int $x): ?string {
+    return null;
+}
+
+class Foo {
+    public ?Foo $foo;
+
+    public function bar(?\Bar\Baz $baz = null): ?self {
+        return $this;
+    }
+}
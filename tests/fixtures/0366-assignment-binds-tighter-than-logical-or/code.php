@@ -0,0 +1,3 @@
+<?php
+
+$result = doThing() or die();
@@ -0,0 +1,4 @@
+<?php
+
+#[Route(defaults: new Defaults())]
+function foo() {}
@@ -0,0 +1,5 @@
+<?php
+
+function f(): never { throw new E; }
+
+function g(): void {}
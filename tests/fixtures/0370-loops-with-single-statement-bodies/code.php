@@ -0,0 +1,10 @@
+<?php
+
+while ($x) doThing();
+
+for (;;) $i++;
+
+foreach ($items as $item) doThing($item);
+
+while ($x)
+    while ($y) $y--;
@@ -0,0 +1,9 @@
+<?php
+
+function a(true $a): true {}
+
+function b(false $b): false {}
+
+function c(null $c): null {}
+
+function d(int|false $d): string|null|false {}
@@ -0,0 +1,11 @@
+<?php
+
+class Point {
+    public function __construct(
+        private int $x,
+        public readonly int $y,
+        int $z = 0,
+        readonly protected ?string $label = null,
+        array ...$rest,
+    ) {}
+}
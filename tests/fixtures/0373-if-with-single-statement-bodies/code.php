@@ -0,0 +1,14 @@
+<?php
+
+function foo($x) {
+    if ($x) return;
+
+    if ($x) a(); else b();
+
+    if ($x) a();
+    elseif ($y) b();
+    elseif ($z) c();
+    else d();
+
+    if ($x) a(); else if ($y) b(); else c();
+}
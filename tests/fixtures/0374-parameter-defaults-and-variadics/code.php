@@ -0,0 +1,5 @@
+<?php
+
+function f($a = 1, $b = 'b', $c = [1, 2], $d = self::FOO, $e = \PHP_INT_MAX * 2, ?int $g = null, ...$rest) {}
+
+fn (int ...$numbers) => $numbers;
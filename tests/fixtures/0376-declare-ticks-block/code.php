@@ -0,0 +1,9 @@
+<?php
+
+function foo() {
+    declare(ticks=1) { echo 1; echo 2; }
+
+    if (true) {
+        declare(ticks=1): echo 3; echo 4; enddeclare;
+    }
+}
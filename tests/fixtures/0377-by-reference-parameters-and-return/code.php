@@ -0,0 +1,21 @@
+<?php
+
+function f(&$x, array &...$rest) {}
+
+function &g() {
+    static $value;
+
+    return $value;
+}
+
+class Foo {
+    public function &bar(int &$x) {
+        return $x;
+    }
+}
+
+$closure = function &() use (&$value) {
+    return $value;
+};
+
+$arrow = fn &(&$x) => $x;
@@ -0,0 +1,8 @@
+<?php
+
+--$x;
+- -$x;
+- - $x;
++-5;
+!!$x;
+~~$x;
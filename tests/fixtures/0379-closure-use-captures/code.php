@@ -0,0 +1,9 @@
+<?php
+
+$a = function () use ($a, $b) {};
+
+$b = function () use (&$a, &$b) {};
+
+$c = function () use ($a, &$b,) {};
+
+$d = static function () use (&$x) {};
@@ -0,0 +1,8 @@
+<?php
+
+++$a->b;
+$a->b++;
+++$arr['k'];
+--$arr['k']['j'];
+$obj::$static++;
+++$a->b->c[0];
@@ -0,0 +1,4 @@
+<?php
+
+++5;
+foo()--;
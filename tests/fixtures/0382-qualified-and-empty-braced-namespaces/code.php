@@ -0,0 +1,7 @@
+<?php
+
+namespace Foo\Bar {
+    class Baz {}
+}
+
+namespace {}
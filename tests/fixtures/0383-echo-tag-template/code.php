@@ -0,0 +1,9 @@
+<html>
+    <head>
+        <title><?= $title ?></title>
+    </head>
+    <body>
+        <h1><?= strtoupper($title); ?></h1>
+        <p><?= $first, $second ?></p>
+    </body>
+</html>
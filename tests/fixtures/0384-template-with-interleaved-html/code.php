@@ -0,0 +1,22 @@
+<ul>
+<?php foreach ($items as $item): ?>
+    <li><?= $item ?></li>
+<?php endforeach; ?>
+</ul>
+<?php if ($show): ?>
+    <p>shown</p>
+<?php elseif ($other): ?>
+    <p>other</p>
+<?php else: ?>
+    <p>hidden</p>
+<?php endif; ?>
+<?php while ($x): ?>
+    <?php $x--; ?>
+<?php endwhile ?>
+<?php for ($i = 0; $i < 3; $i++): ?>
+    <span><?= $i ?></span>
+<?php endfor; ?>
+<?php switch ($a): ?>
+<?php case 1: ?>
+    one
+<?php endswitch; ?>
@@ -0,0 +1,15 @@
+<?php $x = 1 ?>
+<?php echo $x ?>
+<?php return ?>
+<?php global $y ?>
+<?php static $z = 1 ?>
+<?php unset($x) ?>
+<?php const FOO = 1 ?>
+<?php while ($x): while ($y): ?>
+<?php break ?>
+<?php continue 2 ?>
+<?php endwhile; endwhile ?>
+<?php use Foo\Bar ?>
+<?php throw $e ?>
+<?php goto foo ?>
+<?php do {} while ($x) ?>
@@ -0,0 +1,8 @@
+<?php
+
+$a =& $b;
+$a = &$b->c;
+$a = &$b[0];
+$a = &Foo::$bar;
+$a =& foo();
+$a =& $b->c();
@@ -0,0 +1,7 @@
+<?php
+
+$$x;
+$$$x;
+${ $name };
+${ 'a' . 'b' };
+$${'a'};
@@ -0,0 +1,11 @@
+<?php
+
+$obj->$property;
+$obj->{$expr};
+$obj->$method();
+$obj->$method(...);
+$obj->{'get' . $name}($a);
+$obj?->$property;
+$obj?->{$name}();
+Foo::$method();
+Foo::{$method}();
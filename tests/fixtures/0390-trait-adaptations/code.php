@@ -0,0 +1,12 @@
+<?php
+
+class Foo {
+    use A, B {
+        A::foo insteadof B;
+        B::foo as bar;
+        A::baz insteadof B, C;
+        qux as protected;
+        B::qux as protected quux;
+        corge as private grault;
+    }
+}
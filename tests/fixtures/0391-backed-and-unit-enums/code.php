@@ -0,0 +1,23 @@
+<?php
+
+enum Suit: string {
+    case Hearts = 'H';
+    case Spades = 'S';
+}
+
+enum Status: INT {
+    case Active = 1;
+    case Inactive = 2;
+}
+
+enum Direction {
+    case Up;
+    case Down;
+
+    public function opposite(): self {
+        return match ($this) {
+            self::Up => self::Down,
+            self::Down => self::Up,
+        };
+    }
+}
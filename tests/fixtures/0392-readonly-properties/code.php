@@ -0,0 +1,8 @@
+<?php
+
+class Foo {
+    public readonly int $x;
+    private readonly string $y;
+    readonly protected ?Bar $z;
+    readonly array $values;
+}
@@ -0,0 +1,9 @@
+<?php
+
+class Foo {
+    public int $x;
+    protected ?Foo $y;
+    private int|string|null $union;
+    public static array $z = [];
+    var $untyped;
+}
@@ -0,0 +1,7 @@
+<?php
+
+abstract class Foo {
+    abstract protected function f();
+
+    final public static function g() {}
+}
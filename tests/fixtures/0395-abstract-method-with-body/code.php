@@ -0,0 +1,5 @@
+<?php
+
+abstract class Foo {
+    abstract public function bar() {}
+}
@@ -0,0 +1,14 @@
+<?php
+
+#[Route('/x', methods: ['GET'])]
+#[Deprecated, Pure]
+class Controller {
+    #[Inject(new Service(name: 'foo'))]
+    public Service $service;
+
+    #[Route('/y', methods: ['GET', 'POST']), Cache(ttl: 60)]
+    public function action(#[FromQuery('id')] int $id) {}
+}
+
+#[Attribute(Attribute::TARGET_FUNCTION | Attribute::TARGET_METHOD)]
+function foo() {}
@@ -0,0 +1,10 @@
+<?php
+
+function f(#[A] int $x, #[B(1), C] #[D] $y) {}
+
+class Foo {
+    public function __construct(
+        #[Inject] private Service $service,
+        #[SensitiveParameter] string $password,
+    ) {}
+}
@@ -0,0 +1,24 @@
+<?php
+
+/**
+ * Add two numbers.
+ *
+ * @param int $a
+ * @param int $b
+ */
+function add(int $a, int $b): int {
+    return $a + $b;
+}
+
+/** A point. */
+class Point {
+    /** @var int */
+    public int $x;
+
+    /**
+     * @return int
+     */
+    public function x(): int {
+        return $this->x;
+    }
+}
@@ -0,0 +1,16 @@
+<?php
+
+// leading
+$a = 1; // trailing
+
+# hash
+echo $a;
+
+if ($a) {
+    /* block */
+    foo();
+    // last in block
+}
+
+/** Documented. */
+function foo() {}
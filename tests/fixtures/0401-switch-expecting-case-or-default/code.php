@@ -0,0 +1,5 @@
+<?php
+
+switch ($a) {
+    echo $a;
+}
@@ -0,0 +1,7 @@
+<?php
+
+class Foo {
+    public static function bar() {
+        return $this->baz;
+    }
+}
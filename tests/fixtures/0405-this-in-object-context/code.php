@@ -0,0 +1,21 @@
+<?php
+
+class Foo {
+    public function bar() {
+        return $this;
+    }
+
+    public static function baz() {
+        return function () {
+            return $this;
+        };
+    }
+}
+
+function qux() {
+    return new class {
+        public function quux() {
+            return $this;
+        }
+    };
+}
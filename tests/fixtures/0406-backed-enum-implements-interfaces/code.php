@@ -0,0 +1,11 @@
+<?php
+
+enum Status: string implements HasLabel, \JsonSerializable
+{
+    case Active = 'active';
+
+    public function label(): string
+    {
+        return ucfirst($this->value);
+    }
+}
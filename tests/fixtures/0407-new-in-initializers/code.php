@@ -0,0 +1,13 @@
+<?php
+
+const LOGGER = new Logger();
+
+function f($x = new Foo(1)) {
+    static $cache = new ArrayObject();
+}
+
+class Service {
+    public function __construct(
+        private Logger $logger = new NullLogger(),
+    ) {}
+}
@@ -0,0 +1,3 @@
+<?php
+
+$merged = [...$defaults, 'b' => 2, ...$overrides, 3];
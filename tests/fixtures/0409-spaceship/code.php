@@ -0,0 +1,5 @@
+<?php
+
+usort($items, fn ($a, $b) => $a <=> $b);
+
+$before = ($a <=> $b) === -1;
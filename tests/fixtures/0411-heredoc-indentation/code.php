@@ -0,0 +1,9 @@
+<?php
+
+$a = <<<EOT
+    foo
+      bar
+
+    baz $x qux
+    end
+    EOT;
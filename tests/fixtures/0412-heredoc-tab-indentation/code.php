@@ -0,0 +1,8 @@
+<?php
+
+function foo() {
+	return <<<EOT
+		Hello,
+			{$name}!
+		EOT;
+}
@@ -0,0 +1,6 @@
+<?php
+
+$a = <<<'EOT'
+    Hello, $name!
+    {$user->name} and ${var}
+    EOT;
@@ -0,0 +1,8 @@
+<?php
+
+$decimal = 42;
+$hex = 0x1A;
+$octal = 0o17;
+$legacyOctal = 017;
+$binary = 0b101;
+$overflow = 0xFFFFFFFFFFFFFFFF;
@@ -0,0 +1,8 @@
+<?php
+
+$million = 1_000_000;
+$mask = 0xFF_FF;
+$bits = 0b1010_0101;
+$octal = 0o7_7;
+$pi = 3.14_15;
+$avogadro = 6.022_140e2_3;
@@ -0,0 +1,3 @@
+<?php
+
+$mask = 0x_FF;
@@ -0,0 +1,9 @@
+<?php
+
+$a = 1.5;
+$b = .5;
+$c = 1.;
+$d = 1.5e10;
+$e = 1E-3;
+$f = 2e+2;
+$g = 9223372036854775808;
@@ -0,0 +1,10 @@
+<?php
+
+$hex = "\x41\x4a\x7";
+$octal = "\101\60\7\377";
+$unicode = "\u{48}\u{e9}\u{1F600}";
+$simple = "\n\t\$\"\\";
+$unknown = "\q\u";
+$heredoc = <<<EOT
+    \x41\101\u{1F600}\$name
+    EOT;
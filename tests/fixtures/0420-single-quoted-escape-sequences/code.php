@@ -0,0 +1,4 @@
+<?php
+
+$single = '\n \t \x41 \$a \' \\ \q';
+$double = "\n";
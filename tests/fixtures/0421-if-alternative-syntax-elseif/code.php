@@ -0,0 +1,11 @@
+<?php
+
+if ($a):
+    echo 1;
+elseif ($b):
+    echo 2;
+elseif ($c):
+    echo 3;
+else:
+    echo 4;
+endif;
@@ -0,0 +1,13 @@
+<?php
+
+if ($a) {
+    echo 1;
+} else if ($b) {
+    echo 2;
+} elseif ($c) {
+    echo 3;
+} else if ($d) {
+    echo 4;
+} else {
+    echo 5;
+}
@@ -0,0 +1,12 @@
+<?php
+
+switch ($a) {
+    case 1:
+        echo 'one';
+        break;
+    default:
+        echo 'default';
+        break;
+    default:
+        echo 'another default';
+}
@@ -0,0 +1,6 @@
+<?php
+
+echo match ($a) {
+    1 => 'one',
+    default => 'other',
+};
@@ -0,0 +1,9 @@
+<?php
+
+readonly class Point
+{
+    public function __construct(
+        public int $x,
+        public int $y,
+    ) {}
+}
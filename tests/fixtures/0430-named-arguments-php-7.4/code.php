@@ -0,0 +1,3 @@
+<?php
+
+foo(bar: 1, baz: 2);
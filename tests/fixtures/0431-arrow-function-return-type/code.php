@@ -0,0 +1,3 @@
+<?php
+
+$double = fn(int $x): int => $x * 2;
@@ -0,0 +1,3 @@
+<?php
+
+$value = fn &(array &$items) => $items[0];
@@ -0,0 +1,5 @@
+<?php
+
+$foo = new class (1, 2) extends Base implements Foo, Bar {
+    public function __construct(public int $a, public int $b) {}
+};
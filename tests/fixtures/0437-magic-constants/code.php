@@ -0,0 +1,18 @@
+<?php
+
+namespace App;
+
+trait Loggable
+{
+    public function context(): array
+    {
+        return [
+            __DIR__ . '/' . __FILE__,
+            __LINE__ + 1,
+            __CLASS__,
+            __FUNCTION__,
+            __METHOD__,
+            __NAMESPACE__ . '\\' . __TRAIT__,
+        ];
+    }
+}
@@ -0,0 +1,5 @@
+<?php
+
+$a instanceof Foo;
+$a instanceof $b;
+$a instanceof static;
@@ -0,0 +1,4 @@
+<?php
+
+$a['k'] ??= compute();
+$a ??= $b ??= $c;
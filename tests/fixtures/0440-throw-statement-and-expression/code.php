@@ -0,0 +1,5 @@
+<?php
+
+throw new Exception();
+
+$x = $c ?? throw new InvalidArgumentException();
@@ -0,0 +1,8 @@
+<?php
+
+function foo() {
+    goto end;
+}
+
+end:
+echo 'done';
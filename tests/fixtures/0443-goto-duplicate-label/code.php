@@ -0,0 +1,9 @@
+<?php
+
+start:
+echo 'first';
+
+start:
+echo 'second';
+
+goto start;
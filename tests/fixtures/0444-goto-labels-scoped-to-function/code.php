@@ -0,0 +1,16 @@
+<?php
+
+retry:
+$ok = attempt();
+
+if (!$ok) {
+    goto retry;
+}
+
+function attempt() {
+    retry:
+    goto done;
+
+    done:
+    return true;
+}
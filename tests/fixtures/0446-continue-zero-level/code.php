@@ -0,0 +1,5 @@
+<?php
+
+foreach ($items as $item) {
+    continue 0;
+}
@@ -0,0 +1,8 @@
+<?php
+
+foreach ($a as $b) {
+    switch ($b) {
+        case 1:
+            break 3;
+    }
+}
@@ -0,0 +1,9 @@
+<?php
+
+class Foo
+{
+    const A = 1;
+    public const B = 2;
+    protected const C = 3;
+    private const D = 4, E = 5;
+}
@@ -0,0 +1,8 @@
+<?php
+
+class Foo
+{
+    final const A = 1;
+    final public const B = 2;
+    protected final const C = 3;
+}
@@ -0,0 +1,6 @@
+<?php
+
+enum Suit: string
+{
+    final case Hearts = "H";
+}
@@ -0,0 +1,5 @@
+<?php
+
+}
+
+echo "hello";
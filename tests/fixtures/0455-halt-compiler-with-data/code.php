@@ -0,0 +1,7 @@
+<?php
+
+echo "before";
+
+__halt_compiler ( ) ;raw data
+<?php echo "not parsed"; ?>
+binary
@@ -0,0 +1,4 @@
+<?php
+
+__halt_compiler() ?>
+data
\ No newline at end of file
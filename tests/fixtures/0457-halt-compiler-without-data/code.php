@@ -0,0 +1,3 @@
+<?php
+
+__halt_compiler();
\ No newline at end of file
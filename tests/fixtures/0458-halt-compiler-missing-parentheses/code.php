@@ -0,0 +1,5 @@
+<?php
+
+__halt_compiler;
+
+data
@@ -0,0 +1,10 @@
+<?php
+
+function foo($a, $b,) {}
+
+class Foo {
+    public function __construct(
+        public int $a,
+        private int $b,
+    ) {}
+}
@@ -0,0 +1,5 @@
+<?php
+
+foo(1, 2,);
+$foo->bar($a, b: 2,);
+new Foo(1,);
@@ -0,0 +1,3 @@
+<?php
+
+$foo = function () use ($a, $b,) {};
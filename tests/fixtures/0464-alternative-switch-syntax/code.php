@@ -0,0 +1,3 @@
+<?php
+
+switch ($x): case 1: echo 1; endswitch;
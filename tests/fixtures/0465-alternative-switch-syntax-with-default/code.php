@@ -0,0 +1,10 @@
+<?php
+
+switch ($x):
+    case 1:
+    case 2:
+        echo 1;
+        break;
+    default:
+        echo 2;
+endswitch;
@@ -0,0 +1,6 @@
+<?php
+
+switch ($x):
+    case 1:
+        echo 1;
+}
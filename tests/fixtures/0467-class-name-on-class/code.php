@@ -0,0 +1,5 @@
+<?php
+
+Foo::class;
+\Foo\Bar::CLASS;
+static::class;
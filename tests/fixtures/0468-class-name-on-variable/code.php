@@ -0,0 +1,4 @@
+<?php
+
+$x::class;
+$x->y::class;
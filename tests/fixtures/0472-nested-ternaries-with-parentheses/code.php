@@ -0,0 +1,5 @@
+<?php
+
+($a ? $b : $c) ? $d : $e;
+$a ? $b : ($c ? $d : $e);
+$a ? $b ? $c : $d : $e;
@@ -0,0 +1,9 @@
+<?php
+
+namespace Foo\List;
+
+use Bar\Function\Baz;
+
+function foo(\Foo\Class\Bar $bar): Foo\Array {
+    return new Foo\Echo\Bar();
+}
@@ -0,0 +1,5 @@
+<?php
+
+namespace Foo\List\Bar;
+
+new \Foo\Class();
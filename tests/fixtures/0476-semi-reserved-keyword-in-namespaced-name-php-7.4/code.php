@@ -0,0 +1,5 @@
+<?php
+
+namespace Foo\Enum\Readonly;
+
+new \Foo\Match\Parent();
@@ -0,0 +1,4 @@
+<?php
+
+#[Attr]
+function foo() {}
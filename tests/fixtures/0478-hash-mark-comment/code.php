@@ -0,0 +1,4 @@
+<?php
+
+#comment
+function foo() {}
@@ -0,0 +1,7 @@
+<?php
+
+# [NotAnAttribute]
+#[Attr] # comment
+#comment
+#[Other]
+function foo() {}
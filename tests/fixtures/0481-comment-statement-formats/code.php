@@ -0,0 +1,6 @@
+<?php
+
+// single line
+# hash mark
+/* multi line */
+/** document */
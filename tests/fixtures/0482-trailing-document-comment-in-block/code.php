@@ -0,0 +1,7 @@
+<?php
+
+function foo() {
+    echo 1;
+
+    /** @todo finish this */
+}
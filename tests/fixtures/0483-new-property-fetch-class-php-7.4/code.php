@@ -0,0 +1,3 @@
+<?php
+
+new $a->b();
@@ -0,0 +1,5 @@
+<?php
+
+new $a->b[0]?->c();
+new $a::$b;
+new Foo::$bar();
@@ -0,0 +1,9 @@
+<?php
+
+interface I {
+    function f() {}
+
+    public static function g(): void {
+        return;
+    }
+}
@@ -0,0 +1 @@
+<p><?= /* title */ $title ?></p>
@@ -0,0 +1,7 @@
+<?php
+
+class A {
+    private const int X = 1;
+    public const ?string Y = null, Z = 'z';
+    const A|B C = new A;
+}
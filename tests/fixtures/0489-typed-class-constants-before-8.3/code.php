@@ -0,0 +1,5 @@
+<?php
+
+class A {
+    private const int X = 1;
+}
@@ -0,0 +1,8 @@
+<?php
+
+while (true) {
+    break 0;
+    continue $x + 1;
+    break (0);
+    continue 2;
+}
@@ -0,0 +1,8 @@
+<?php
+
+echo 1;
+
+__halt_compiler /* a */ ( // b
+) # c
+;
+foo(<?php $bar; ?>
@@ -0,0 +1,10 @@
+<?php foreach ($items as $item): ?>
+    <li><?= $item ?></li>
+    <? if ($item->active): ?>
+        <b>active</b>
+    <? endif; ?>
+<? endforeach; ?>
+<? switch ($a): ?>
+<? case 1: ?>
+    one
+<? endswitch;
@@ -0,0 +1,6 @@
+<?php
+
+#[Route(defaults: new Defaults())]
+function f() {}
+
+function g($a = new Foo) {}
@@ -0,0 +1,3 @@
+<?php
+
+1 ? 2 : 3 ?: 4;
@@ -3,13 +3,27 @@ use std::fs::read_dir;
 use std::io;
 use std::path::PathBuf;
 
+use php_parser_rs::lexer::Lexer;
+use php_parser_rs::printer::print;
+use php_parser_rs::ParserConfig;
 use pretty_assertions::assert_str_eq;
+use serde::Deserialize;
 
 struct TestFixture {
     fixture: String,
     code_file: PathBuf,
     ast_file: PathBuf,
     error_file: PathBuf,
+    config_file: PathBuf,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct TestFixtureConfig {
+    #[serde(flatten)]
+    parser: ParserConfig,
+    // re-lex and re-parse the printed tokens, and compare the resulting ast.
+    roundtrip: bool,
 }
 
 struct ExpectedTestResult {
@@ -24,6 +38,7 @@ impl TestFixture {
             code_file: entry.join("code.php"),
             ast_file: entry.join("ast.txt"),
             error_file: entry.join("error.txt"),
+            config_file: entry.join("config.json"),
         }
     }
 
@@ -31,6 +46,12 @@ impl TestFixture {
         std::fs::read_to_string(&self.code_file).unwrap_or_default()
     }
 
+    fn config(&self) -> TestFixtureConfig {
+        std::fs::read_to_string(&self.config_file)
+            .map(|config| serde_json::from_str(&config).unwrap())
+            .unwrap_or_default()
+    }
+
     fn validate(&self) -> io::Result<()> {
         if !self.code_file.exists() {
             return Err(io::Error::new(
@@ -89,20 +110,27 @@ fn test_fixtures() -> io::Result<()> {
 
 fn run_test(test_fixture: &TestFixture) -> io::Result<()> {
     let code = test_fixture.code();
+    let config = test_fixture.config();
     let expected = test_fixture.expected();
 
     if !expected.ast.is_empty() {
-        let ast = php_parser_rs::parse(&code).unwrap();
+        let ast = php_parser_rs::parse_with_config(&code, config.parser).unwrap();
         assert_str_eq!(
             expected.ast.trim(),
             format!("{:#?}", ast),
             "ast mismatch for fixture `{}`",
             test_fixture.fixture
         );
+
+        if config.roundtrip {
+            assert_roundtrip(&code, config.parser, &test_fixture.fixture);
+        }
     }
 
     if !expected.error.is_empty() {
-        let error = php_parser_rs::parse(&code).err().unwrap();
+        let error = php_parser_rs::parse_with_config(&code, config.parser)
+            .err()
+            .unwrap();
 
         assert_str_eq!(
             expected.error.trim(),
@@ -116,3 +144,22 @@ fn run_test(test_fixture: &TestFixture) -> io::Result<()> {
 
     Ok(())
 }
+
+/// Lex and parse the given source, print the tokens back, then lex and parse
+/// the printed source again, asserting that both passes produce the same ast.
+fn assert_roundtrip(code: &str, config: ParserConfig, fixture: &str) {
+    let tokens = Lexer::new().tokenize(code.as_bytes()).unwrap();
+    let ast = php_parser_rs::construct_with_config(&tokens, config).unwrap();
+
+    let printed = print(&tokens);
+
+    let reprinted_tokens = Lexer::new().tokenize(printed.as_bytes()).unwrap();
+    let reprinted_ast = php_parser_rs::construct_with_config(&reprinted_tokens, config).unwrap();
+
+    assert_str_eq!(
+        format!("{:#?}", ast),
+        format!("{:#?}", reprinted_ast),
+        "roundtrip ast mismatch for fixture `{}`",
+        fixture
+    );
+}
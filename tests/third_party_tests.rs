@@ -5,11 +5,8 @@ use std::process::Command;
 use std::thread;
 
 use php_parser_rs::lexer::Lexer;
-
-enum TestResult {
-    Success,
-    Error(String),
-}
+use rayon::prelude::*;
+use rayon::ThreadPoolBuilder;
 
 #[test]
 fn php_standard_library() {
@@ -407,59 +404,47 @@ fn test_repository(name: &str, repository: &str, ignore: &[&str]) {
 
     let entries = read_directory(name, out_path.clone(), out_path, ignore);
 
-    let thread = thread::Builder::new()
+    // parsing deeply nested code needs a larger stack than rayon's default.
+    let pool = ThreadPoolBuilder::new()
         .stack_size(16 * 1024 * 1024)
-        .name(name.to_string())
-        .spawn(move || {
-            let thread = thread::current();
-            let thread_name = thread.name().unwrap();
+        .thread_name({
+            let name = name.to_string();
+            move |index| format!("{name}-{index}")
+        })
+        .build()
+        .unwrap_or_else(|e| panic!("failed to build thread pool: {:#?}", e));
 
-            let mut results = vec![];
-            for (name, filename) in entries {
+    let failures = pool.install(|| {
+        entries
+            .par_iter()
+            .filter_map(|(name, filename)| {
                 let code = std::fs::read(filename).unwrap();
+                let thread = thread::current();
+                let thread_name = thread.name().unwrap();
 
                 match Lexer::new().tokenize(&code) {
                     Ok(tokens) => match php_parser_rs::construct(&tokens) {
-                        Ok(_) => {
-                            results.push(TestResult::Success);
-                        }
+                        Ok(_) => None,
                         Err(error) => {
-                            results.push(TestResult::Error(format!(
-                                "❌ [{thread_name}][{name}]: {error} ({error:?})"
-                            )));
+                            Some(format!("❌ [{thread_name}][{name}]: {error} ({error:?})"))
                         }
                     },
-                    Err(error) => {
-                        results.push(TestResult::Error(format!(
-                            "❌ [{thread_name}][{name}]: {error} ({error:?})"
-                        )));
-                    }
+                    Err(error) => Some(format!("❌ [{thread_name}][{name}]: {error} ({error:?})")),
                 }
-            }
-
-            results
-        });
+            })
+            .collect::<Vec<String>>()
+    });
 
-    let result = thread
-        .unwrap_or_else(|e| panic!("failed to spawn thread: {:#?}", e))
-        .join()
-        .unwrap_or_else(|e| panic!("failed to join thread: {:#?}", e));
-
-    let mut fail = false;
-    result
-        .iter()
-        .map(|result| match result {
-            TestResult::Error(message) => {
-                fail = true;
-
-                println!("{}", message);
-            }
-            TestResult::Success => {}
-        })
-        .for_each(drop);
+    for failure in &failures {
+        println!("{}", failure);
+    }
 
-    if fail {
-        panic!();
+    if !failures.is_empty() {
+        panic!(
+            "{} of {} files failed to parse",
+            failures.len(),
+            entries.len()
+        );
     }
 }
 